@@ -25,11 +25,15 @@ fn main() {
     let noise_generator = OpenSimplexNoise::new(Some(883_279_212_983_182_319));
     let scale = 0.044;
 
-    for x in 0..WIN_SIZE[0] {
-        for y in 0..WIN_SIZE[1] {
-            let value = noise_generator.eval_2d(x as f64 * scale, y as f64 * scale) as f32;
-            noise_map[get_id_from_pos(x, y)] = (value + 1.0) * 1.0 / 2.0;
-        }
+    noise_generator.fill_2d_f32(
+        &mut noise_map,
+        WIN_SIZE[0] as usize,
+        WIN_SIZE[1] as usize,
+        [0.0, 0.0],
+        scale,
+    );
+    for value in noise_map.iter_mut() {
+        *value = (*value + 1.0) * 1.0 / 2.0;
     }
 
     let sdl_context = sdl2::init().unwrap();
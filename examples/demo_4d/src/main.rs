@@ -7,6 +7,7 @@ use piston::event_loop::*;
 use opensimplex_noise_rs::OpenSimplexNoise;
 
 const WIN_SIZE: [i32; 2] = [400, 400];
+const LOOP_LENGTH: f64 = 20.0;
 
 fn main() {
     let opengl = OpenGL::V3_2;
@@ -22,14 +23,13 @@ fn main() {
 
     let mut gl = GlGraphics::new(opengl);
     let mut time = 0.0;
-    let mut component_4d = 0.0;
+    let mut radius = 1.0;
     let mut events = Events::new(EventSettings::new());
     while let Some(e) = events.next(&mut window) {
         if let Some(Button::Keyboard(key)) = e.press_args() {
             if key == Key::Space {
-                component_4d += 0.1;
-                time -= 0.1;
-                println!("You are moving in 4'th dimension");
+                radius += 0.1;
+                println!("The loop goes through more of the 4'th dimension");
             }
         };
         if let Some(args) = e.render_args() {
@@ -44,7 +44,7 @@ fn main() {
                 for x in 0..WIN_SIZE[0] {
                     for y in 0..WIN_SIZE[1] {
                         let mut value =
-                            noise_generator.eval_4d(x as f64 * scale, y as f64 * scale, time, component_4d)
+                            noise_generator.eval_2d_looping(x as f64 * scale, y as f64 * scale, time, LOOP_LENGTH, radius)
                                 as f32;
                         value = (value + 1.0) * 1.0 / 2.0;
 
@@ -0,0 +1,49 @@
+use core::convert::TryInto;
+
+use wide::{f64x4, CmpGt};
+
+use super::super::utils;
+use super::super::vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+pub const LANES: usize = 4;
+
+pub type Lanes = f64x4;
+
+pub fn load2(xs: &[f64], ys: &[f64]) -> Vec2<Lanes> {
+    Vec2::new(load(xs), load(ys))
+}
+
+pub fn load3(xs: &[f64], ys: &[f64], zs: &[f64]) -> Vec3<Lanes> {
+    Vec3::new(load(xs), load(ys), load(zs))
+}
+
+pub fn load4(xs: &[f64], ys: &[f64], zs: &[f64], ws: &[f64]) -> Vec4<Lanes> {
+    Vec4::new(load(xs), load(ys), load(zs), load(ws))
+}
+
+fn load(values: &[f64]) -> Lanes {
+    let values: [f64; LANES] = values.try_into().unwrap();
+    Lanes::from(values)
+}
+
+pub fn splat(value: f64) -> Lanes {
+    Lanes::splat(value)
+}
+
+// Floors lane by lane the same way as the scalar evaluators, so huge inputs saturate alike.
+pub fn floor(value: Lanes) -> Lanes {
+    let value = value.to_array();
+    Lanes::from([
+        utils::to_f64(utils::floor(value[0])),
+        utils::to_f64(utils::floor(value[1])),
+        utils::to_f64(utils::floor(value[2])),
+        utils::to_f64(utils::floor(value[3])),
+    ])
+}
+
+// attn^4 * extrapolation where attn is positive, like `utils::contribute`.
+pub fn attenuate(attn: Lanes, extrapolation: Lanes) -> Lanes {
+    let squared = attn * attn;
+    attn.cmp_gt(Lanes::ZERO)
+        .blend(squared * squared * extrapolation, Lanes::ZERO)
+}
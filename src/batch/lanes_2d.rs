@@ -0,0 +1,72 @@
+use wide::{CmpEq, CmpGt, CmpLe, CmpLt};
+
+use super::super::open_simplex_noise_2d::{OpenSimplexNoise2D, NORMALIZING_SCALAR};
+use super::super::vector::{vec2::Vec2, VecMethods};
+use super::super::{LatticeHash, NoiseEvaluator, NoiseVersion};
+use super::lanes::{self, Lanes};
+
+// Same as `OpenSimplexNoise2D::eval`, with the choice of the triangle and of its extra
+// lattice point made by masks instead of branches.
+pub fn eval<H: LatticeHash<Vec2<f64>>>(
+    input: Vec2<Lanes>,
+    perm: &H,
+    version: NoiseVersion,
+) -> Lanes {
+    let stretch = input + (OpenSimplexNoise2D::STRETCH_POINT.map(lanes::splat) * input.sum());
+    let grid = stretch.map(lanes::floor);
+
+    let squashed = grid + (OpenSimplexNoise2D::SQUISH_POINT.map(lanes::splat) * grid.sum());
+    let ins = stretch - grid;
+    let origin = input - squashed;
+
+    let contribute = |x: Lanes, y: Lanes| contribute(Vec2::new(x, y), origin, grid, perm);
+    let (zero, one) = (Lanes::ZERO, Lanes::ONE);
+
+    // Either the (0, 0) or the (1, 1) triangle
+    let in_sum = ins.sum();
+    let factor = in_sum.cmp_le(one).blend(zero, one);
+    let zins = one + factor - in_sum;
+
+    let approached_from_below = if version == NoiseVersion::V0_3 {
+        factor.cmp_eq(factor)
+    } else {
+        factor.cmp_eq(zero)
+    };
+    let is_closer =
+        |score: Lanes| approached_from_below.blend(zins.cmp_gt(score), zins.cmp_lt(score));
+
+    let is_factor_closest = is_closer(ins.x) | is_closer(ins.y);
+    let is_x_closer = ins.x.cmp_gt(ins.y);
+    let point = Vec2::new(
+        is_factor_closest.blend(is_x_closer.blend(one + factor, -one + factor), one - factor),
+        is_factor_closest.blend(is_x_closer.blend(-one + factor, one + factor), one - factor),
+    );
+
+    let value = contribute(one, zero)
+        + contribute(zero, one)
+        + (contribute(zero + factor, zero + factor) + contribute(point.x, point.y));
+
+    value / lanes::splat(NORMALIZING_SCALAR)
+}
+
+fn contribute<H: LatticeHash<Vec2<f64>>>(
+    delta: Vec2<Lanes>,
+    origin: Vec2<Lanes>,
+    grid: Vec2<Lanes>,
+    perm: &H,
+) -> Lanes {
+    let shifted = origin - delta - OpenSimplexNoise2D::SQUISH_POINT.map(lanes::splat) * delta.sum();
+    let attn = lanes::splat(2.0) - shifted.get_attenuation_factor();
+
+    let lattice = grid + delta;
+    let (x, y) = (lattice.x.to_array(), lattice.y.to_array());
+    let mut grad = (Lanes::ZERO.to_array(), Lanes::ZERO.to_array());
+    for lane in 0..lanes::LANES {
+        let lane_grad = OpenSimplexNoise2D::get_grad(Vec2::new(x[lane], y[lane]), perm);
+        grad.0[lane] = lane_grad.x;
+        grad.1[lane] = lane_grad.y;
+    }
+    let grad = Vec2::new(Lanes::from(grad.0), Lanes::from(grad.1));
+
+    lanes::attenuate(attn, grad.dot(&shifted))
+}
@@ -0,0 +1,78 @@
+use super::super::open_simplex_noise_3d::{OpenSimplexNoise3D, LATTICE_POINTS, NORMALIZING_SCALAR};
+use super::super::vector::{vec3::Vec3, VecMethods};
+use super::super::{LatticeHash, NoiseEvaluator};
+use super::lanes::{self, Lanes};
+
+const SLOTS: usize = LATTICE_POINTS[0].len();
+
+// Same as `OpenSimplexNoise3D::eval` for the versions summing every lattice point in range.
+// Each lane gets its lattice points from `OpenSimplexNoise3D::lattice_points`, only their
+// contributions are evaluated a few lanes at a time.
+pub fn eval<H: LatticeHash<Vec3<f64>>>(input: Vec3<Lanes>, perm: &H) -> Lanes {
+    let stretch = input + (OpenSimplexNoise3D::STRETCH_POINT.map(lanes::splat) * input.sum());
+    let grid = stretch.map(lanes::floor);
+
+    let squashed = grid + (OpenSimplexNoise3D::SQUISH_POINT.map(lanes::splat) * grid.sum());
+    let ins = stretch - grid;
+    let origin = input - squashed;
+
+    let (x, y, z) = (ins.x.to_array(), ins.y.to_array(), ins.z.to_array());
+    let mut slots = [[[0.0; lanes::LANES]; 3]; SLOTS];
+    for lane in 0..lanes::LANES {
+        let points = OpenSimplexNoise3D::lattice_points(Vec3::new(x[lane], y[lane], z[lane]));
+        for (slot, point) in slots.iter_mut().zip(points) {
+            slot[0][lane] = point.x;
+            slot[1][lane] = point.y;
+            slot[2][lane] = point.z;
+        }
+    }
+
+    let value = slots.iter().fold(Lanes::ZERO, |value, slot| {
+        let delta = Vec3::new(
+            Lanes::from(slot[0]),
+            Lanes::from(slot[1]),
+            Lanes::from(slot[2]),
+        );
+        value + contribute(delta, origin, grid, perm)
+    });
+
+    value / lanes::splat(NORMALIZING_SCALAR)
+}
+
+fn contribute<H: LatticeHash<Vec3<f64>>>(
+    delta: Vec3<Lanes>,
+    origin: Vec3<Lanes>,
+    grid: Vec3<Lanes>,
+    perm: &H,
+) -> Lanes {
+    let shifted = origin - delta - OpenSimplexNoise3D::SQUISH_POINT.map(lanes::splat) * delta.sum();
+    let attn = lanes::splat(2.0) - shifted.get_attenuation_factor();
+
+    let lattice = grid + delta;
+    let (x, y, z) = (
+        lattice.x.to_array(),
+        lattice.y.to_array(),
+        lattice.z.to_array(),
+    );
+    // Lanes out of range contribute nothing whatever their gradient, like in
+    // `utils::contribute` their lattice point isn't hashed
+    let in_range = attn.to_array().map(|attn| attn > 0.0);
+    if !in_range.contains(&true) {
+        return Lanes::ZERO;
+    }
+
+    let mut grad = [Lanes::ZERO.to_array(); 3];
+    for lane in (0..lanes::LANES).filter(|&lane| in_range[lane]) {
+        let lane_grad = OpenSimplexNoise3D::get_grad(Vec3::new(x[lane], y[lane], z[lane]), perm);
+        grad[0][lane] = lane_grad.x;
+        grad[1][lane] = lane_grad.y;
+        grad[2][lane] = lane_grad.z;
+    }
+    let grad = Vec3::new(
+        Lanes::from(grad[0]),
+        Lanes::from(grad[1]),
+        Lanes::from(grad[2]),
+    );
+
+    lanes::attenuate(attn, grad.dot(&shifted))
+}
@@ -0,0 +1,99 @@
+use wide::CmpGt;
+
+use super::super::open_simplex_noise_4d::{OpenSimplexNoise4D, LATTICE_POINTS, NORMALIZING_SCALAR};
+use super::super::vector::{vec4::Vec4, VecMethods};
+use super::super::{LatticeHash, NoiseEvaluator};
+use super::lanes::{self, Lanes};
+
+// The dispentachorons have the most lattice points
+const SLOTS: usize = LATTICE_POINTS[1].len();
+
+// Same as `lanes_3d::eval`. The 4D regions don't all have the same number of lattice points,
+// slots past the end of a lane's region are left out of its sum.
+pub fn eval<H: LatticeHash<Vec4<f64>>>(input: Vec4<Lanes>, perm: &H) -> Lanes {
+    let stretch = input + (OpenSimplexNoise4D::STRETCH_POINT.map(lanes::splat) * input.sum());
+    let grid = stretch.map(lanes::floor);
+
+    let squashed = grid + (OpenSimplexNoise4D::SQUISH_POINT.map(lanes::splat) * grid.sum());
+    let ins = stretch - grid;
+    let origin = input - squashed;
+
+    let (x, y, z, w) = (
+        ins.x.to_array(),
+        ins.y.to_array(),
+        ins.z.to_array(),
+        ins.w.to_array(),
+    );
+    let mut slots = [[[0.0; lanes::LANES]; 4]; SLOTS];
+    let mut counts = [0.0; lanes::LANES];
+    for lane in 0..lanes::LANES {
+        let points =
+            OpenSimplexNoise4D::lattice_points(Vec4::new(x[lane], y[lane], z[lane], w[lane]));
+        for (slot, point) in slots.iter_mut().zip(points) {
+            slot[0][lane] = point.x;
+            slot[1][lane] = point.y;
+            slot[2][lane] = point.z;
+            slot[3][lane] = point.w;
+            counts[lane] += 1.0;
+        }
+    }
+
+    let counts = Lanes::from(counts);
+    let value = slots
+        .iter()
+        .enumerate()
+        .fold(Lanes::ZERO, |value, (index, slot)| {
+            let delta = Vec4::new(
+                Lanes::from(slot[0]),
+                Lanes::from(slot[1]),
+                Lanes::from(slot[2]),
+                Lanes::from(slot[3]),
+            );
+            let in_region = counts.cmp_gt(lanes::splat(index as f64));
+            value + in_region.blend(contribute(delta, origin, grid, perm), Lanes::ZERO)
+        });
+
+    value / lanes::splat(NORMALIZING_SCALAR)
+}
+
+fn contribute<H: LatticeHash<Vec4<f64>>>(
+    delta: Vec4<Lanes>,
+    origin: Vec4<Lanes>,
+    grid: Vec4<Lanes>,
+    perm: &H,
+) -> Lanes {
+    let shifted = origin - delta - OpenSimplexNoise4D::SQUISH_POINT.map(lanes::splat) * delta.sum();
+    let attn = lanes::splat(2.0) - shifted.get_attenuation_factor();
+
+    let lattice = grid + delta;
+    let (x, y, z, w) = (
+        lattice.x.to_array(),
+        lattice.y.to_array(),
+        lattice.z.to_array(),
+        lattice.w.to_array(),
+    );
+    // Lanes out of range contribute nothing whatever their gradient, like in
+    // `utils::contribute` their lattice point isn't hashed
+    let in_range = attn.to_array().map(|attn| attn > 0.0);
+    if !in_range.contains(&true) {
+        return Lanes::ZERO;
+    }
+
+    let mut grad = [Lanes::ZERO.to_array(); 4];
+    for lane in (0..lanes::LANES).filter(|&lane| in_range[lane]) {
+        let lane_grad =
+            OpenSimplexNoise4D::get_grad(Vec4::new(x[lane], y[lane], z[lane], w[lane]), perm);
+        grad[0][lane] = lane_grad.x;
+        grad[1][lane] = lane_grad.y;
+        grad[2][lane] = lane_grad.z;
+        grad[3][lane] = lane_grad.w;
+    }
+    let grad = Vec4::new(
+        Lanes::from(grad[0]),
+        Lanes::from(grad[1]),
+        Lanes::from(grad[2]),
+        Lanes::from(grad[3]),
+    );
+
+    lanes::attenuate(attn, grad.dot(&shifted))
+}
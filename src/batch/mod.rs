@@ -0,0 +1,130 @@
+#[cfg(feature = "simd")]
+mod lanes;
+#[cfg(feature = "simd")]
+mod lanes_2d;
+#[cfg(feature = "simd")]
+mod lanes_3d;
+#[cfg(feature = "simd")]
+mod lanes_4d;
+
+#[cfg(feature = "simd")]
+use super::NoiseVersion;
+use super::{OpenSimplexGenerator, PointHash};
+
+// Batch evaluation of scattered points given as one slice per coordinate. With the `simd`
+// feature points are evaluated a few lanes at a time, without it they are evaluated one by
+// one, and so are 3D and 4D points of the default `NoiseVersion::V0_3`, whose regions only the
+// scalar evaluators implement. Either way `out[i]` is exactly `eval_*d(xs[i], ys[i], ...)`.
+impl<H: PointHash> OpenSimplexGenerator<H> {
+    pub fn eval_2d_batch(&self, xs: &[f64], ys: &[f64], out: &mut [f64]) {
+        assert!(
+            xs.len() == out.len() && ys.len() == out.len(),
+            "coordinate slices don't match the output"
+        );
+
+        for i in self.eval_2d_lanes(xs, ys, out)..out.len() {
+            out[i] = self.eval_2d(xs[i], ys[i]);
+        }
+    }
+
+    pub fn eval_3d_batch(&self, xs: &[f64], ys: &[f64], zs: &[f64], out: &mut [f64]) {
+        assert!(
+            xs.len() == out.len() && ys.len() == out.len() && zs.len() == out.len(),
+            "coordinate slices don't match the output"
+        );
+
+        for i in self.eval_3d_lanes(xs, ys, zs, out)..out.len() {
+            out[i] = self.eval_3d(xs[i], ys[i], zs[i]);
+        }
+    }
+
+    pub fn eval_4d_batch(&self, xs: &[f64], ys: &[f64], zs: &[f64], ws: &[f64], out: &mut [f64]) {
+        assert!(
+            xs.len() == out.len()
+                && ys.len() == out.len()
+                && zs.len() == out.len()
+                && ws.len() == out.len(),
+            "coordinate slices don't match the output"
+        );
+
+        for i in self.eval_4d_lanes(xs, ys, zs, ws, out)..out.len() {
+            out[i] = self.eval_4d(xs[i], ys[i], zs[i], ws[i]);
+        }
+    }
+
+    // Evaluates as many whole lanes as fit into the slices, returns how many points were done.
+    #[cfg(feature = "simd")]
+    fn eval_2d_lanes(&self, xs: &[f64], ys: &[f64], out: &mut [f64]) -> usize {
+        let chunks = out
+            .chunks_exact_mut(lanes::LANES)
+            .zip(xs.chunks_exact(lanes::LANES))
+            .zip(ys.chunks_exact(lanes::LANES));
+        let hash = self.lattice_hash();
+        for ((out, xs), ys) in chunks {
+            let value = lanes_2d::eval(lanes::load2(xs, ys), hash, self.version);
+            out.copy_from_slice(&value.to_array());
+        }
+        out.len() - out.len() % lanes::LANES
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn eval_2d_lanes(&self, _: &[f64], _: &[f64], _: &mut [f64]) -> usize {
+        0
+    }
+
+    #[cfg(feature = "simd")]
+    fn eval_3d_lanes(&self, xs: &[f64], ys: &[f64], zs: &[f64], out: &mut [f64]) -> usize {
+        if self.version == NoiseVersion::V0_3 {
+            return 0;
+        }
+
+        let chunks = out
+            .chunks_exact_mut(lanes::LANES)
+            .zip(xs.chunks_exact(lanes::LANES))
+            .zip(ys.chunks_exact(lanes::LANES))
+            .zip(zs.chunks_exact(lanes::LANES));
+        let hash = self.lattice_hash();
+        for (((out, xs), ys), zs) in chunks {
+            let value = lanes_3d::eval(lanes::load3(xs, ys, zs), hash);
+            out.copy_from_slice(&value.to_array());
+        }
+        out.len() - out.len() % lanes::LANES
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn eval_3d_lanes(&self, _: &[f64], _: &[f64], _: &[f64], _: &mut [f64]) -> usize {
+        0
+    }
+
+    #[cfg(feature = "simd")]
+    fn eval_4d_lanes(
+        &self,
+        xs: &[f64],
+        ys: &[f64],
+        zs: &[f64],
+        ws: &[f64],
+        out: &mut [f64],
+    ) -> usize {
+        if self.version == NoiseVersion::V0_3 {
+            return 0;
+        }
+
+        let chunks = out
+            .chunks_exact_mut(lanes::LANES)
+            .zip(xs.chunks_exact(lanes::LANES))
+            .zip(ys.chunks_exact(lanes::LANES))
+            .zip(zs.chunks_exact(lanes::LANES))
+            .zip(ws.chunks_exact(lanes::LANES));
+        let hash = self.lattice_hash();
+        for ((((out, xs), ys), zs), ws) in chunks {
+            let value = lanes_4d::eval(lanes::load4(xs, ys, zs, ws), hash);
+            out.copy_from_slice(&value.to_array());
+        }
+        out.len() - out.len() % lanes::LANES
+    }
+
+    #[cfg(not(feature = "simd"))]
+    fn eval_4d_lanes(&self, _: &[f64], _: &[f64], _: &[f64], _: &[f64], _: &mut [f64]) -> usize {
+        0
+    }
+}
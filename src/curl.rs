@@ -0,0 +1,35 @@
+use super::{OpenSimplexGenerator, PointHash};
+
+// Offsets of the three potential fields, far enough apart to be decorrelated.
+const DEFAULT_POTENTIAL_OFFSETS: [f64; 3] = [0.0, 113.5, 271.25];
+
+impl<H: PointHash> OpenSimplexGenerator<H> {
+    // Velocity of the flow field with the 2D noise as its stream function.
+    pub fn curl_2d(&self, x: f64, y: f64) -> [f64; 2] {
+        let (_, gradient) = self.eval_2d_with_gradient(x, y);
+        [gradient[1], -gradient[0]]
+    }
+
+    pub fn curl_3d(&self, x: f64, y: f64, z: f64) -> [f64; 3] {
+        self.curl_3d_with_offsets(x, y, z, DEFAULT_POTENTIAL_OFFSETS)
+    }
+
+    // Every component of the vector potential is sampled from the 3D noise shifted by
+    // its own offset on each axis, use different offsets to get a different flow.
+    pub fn curl_3d_with_offsets(&self, x: f64, y: f64, z: f64, offsets: [f64; 3]) -> [f64; 3] {
+        let potential = |offset: f64| {
+            let (_, gradient) = self.eval_3d_with_gradient(x + offset, y + offset, z + offset);
+            gradient
+        };
+
+        let psi_x = potential(offsets[0]);
+        let psi_y = potential(offsets[1]);
+        let psi_z = potential(offsets[2]);
+
+        [
+            psi_z[1] - psi_y[2],
+            psi_x[2] - psi_z[0],
+            psi_y[0] - psi_x[1],
+        ]
+    }
+}
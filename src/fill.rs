@@ -0,0 +1,228 @@
+use super::open_simplex_noise_2d::OpenSimplexNoise2D;
+use super::open_simplex_noise_3d::OpenSimplexNoise3D;
+use super::utils;
+use super::vector::{vec2::Vec2, vec3::Vec3, VecMethods};
+use super::{OpenSimplexGenerator, PointHash};
+
+const MAX_WALKED_CELLS: f64 = 4.0;
+
+// Order of the samples of a volume in its buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VolumeLayout {
+    // buf[(x * size_y + y) * size_z + z], consecutive samples run along Z
+    XMajor,
+    // buf[(z * size_y + y) * size_x + x], consecutive samples run along X
+    ZMajor,
+}
+
+// Batch evaluation over regular grids. `buf` is filled row by row, the sample at column `i`
+// and row `j` being exactly `eval_2d(origin[0] + i * step, origin[1] + j * step)`.
+// Volumes follow the same rule along all three axes.
+impl<H: PointHash> OpenSimplexGenerator<H> {
+    pub fn fill_2d(
+        &self,
+        buf: &mut [f64],
+        width: usize,
+        height: usize,
+        origin: [f64; 2],
+        step: f64,
+    ) {
+        assert_eq!(
+            buf.len(),
+            width * height,
+            "buffer doesn't match the grid size"
+        );
+        for (j, row) in buf.chunks_mut(width.max(1)).enumerate() {
+            self.fill_row_2d(row, j, origin, step, |value| value);
+        }
+    }
+
+    pub fn fill_2d_f32(
+        &self,
+        buf: &mut [f32],
+        width: usize,
+        height: usize,
+        origin: [f64; 2],
+        step: f64,
+    ) {
+        assert_eq!(
+            buf.len(),
+            width * height,
+            "buffer doesn't match the grid size"
+        );
+        for (j, row) in buf.chunks_mut(width.max(1)).enumerate() {
+            self.fill_row_2d(row, j, origin, step, |value| value as f32);
+        }
+    }
+
+    pub fn fill_3d(
+        &self,
+        buf: &mut [f64],
+        size: [usize; 3],
+        origin: [f64; 3],
+        step: f64,
+        layout: VolumeLayout,
+    ) {
+        assert_eq!(
+            buf.len(),
+            size[0] * size[1] * size[2],
+            "buffer doesn't match the volume size"
+        );
+
+        let axes = layout.axes();
+        for (line_index, line) in buf.chunks_mut(size[axes[2]].max(1)).enumerate() {
+            self.fill_line_3d(line, line_index, size, origin, step, axes);
+        }
+    }
+
+    // Same as `fill_3d`, with the sample at (i, j, k) stored at
+    // buf[i * strides[0] + j * strides[1] + k * strides[2]]. Lets chunks be written straight
+    // into a padded or larger volume, the samples in between are left untouched.
+    pub fn fill_3d_strided(
+        &self,
+        buf: &mut [f64],
+        size: [usize; 3],
+        origin: [f64; 3],
+        step: f64,
+        strides: [usize; 3],
+    ) {
+        assert!(!strides.contains(&0), "strides must be non-zero");
+        if size.contains(&0) {
+            return;
+        }
+        let last = (0..3)
+            .map(|axis| (size[axis] - 1) * strides[axis])
+            .sum::<usize>();
+        assert!(
+            last < buf.len(),
+            "buffer is too small for the volume size and strides"
+        );
+
+        // Lines run along the axis with the smallest stride
+        let mut axes = [0, 1, 2];
+        axes.sort_unstable_by_key(|&axis| core::cmp::Reverse(strides[axis]));
+        for line_index in 0..size[axes[0]] * size[axes[1]] {
+            let start = line_index / size[axes[1]] * strides[axes[0]]
+                + line_index % size[axes[1]] * strides[axes[1]];
+            let line = buf[start..]
+                .iter_mut()
+                .step_by(strides[axes[2]])
+                .take(size[axes[2]]);
+            self.fill_line_3d(line, line_index, size, origin, step, axes);
+        }
+    }
+
+    // Neighbouring samples mostly share their lattice cell, so instead of flooring every
+    // stretched point the cell is walked along the row, and its origin is only recomputed
+    // once the walk crosses into another cell. That is all the work shared between samples:
+    // each one still hashes its lattice points and sums their contributions, caching the
+    // gradients of a cell measured slower than looking them up again.
+    pub(crate) fn fill_row_2d<T>(
+        &self,
+        row: &mut [T],
+        j: usize,
+        origin: [f64; 2],
+        step: f64,
+        convert: impl Fn(f64) -> T,
+    ) {
+        let y = origin[1] + j as f64 * step;
+
+        let hash = self.lattice_hash();
+        let mut grid = Vec2::new(f64::NAN, f64::NAN);
+        let mut squashed = grid;
+        for (i, sample) in row.iter_mut().enumerate() {
+            let input = Vec2::new(origin[0] + i as f64 * step, y);
+            let stretch = input + (OpenSimplexNoise2D::STRETCH_POINT * input.sum());
+
+            let next = Vec2::new(walk_floor(stretch.x, grid.x), walk_floor(stretch.y, grid.y));
+            if next.x != grid.x || next.y != grid.y {
+                grid = next;
+                squashed = grid + (OpenSimplexNoise2D::SQUISH_POINT * grid.sum());
+            }
+
+            *sample = convert(OpenSimplexNoise2D::eval_in_cell(
+                input,
+                stretch,
+                grid,
+                squashed,
+                hash,
+                self.version,
+            ));
+        }
+    }
+
+    // Lines run along the fastest varying axis, the cell is walked along them like along
+    // the rows of `fill_2d`.
+    pub(crate) fn fill_line_3d<'a>(
+        &self,
+        line: impl IntoIterator<Item = &'a mut f64>,
+        line_index: usize,
+        size: [usize; 3],
+        origin: [f64; 3],
+        step: f64,
+        axes: [usize; 3],
+    ) {
+        let coord = |axis: usize, i: usize| origin[axis] + i as f64 * step;
+
+        let mut point = [0.0; 3];
+        point[axes[0]] = coord(axes[0], line_index / size[axes[1]]);
+        point[axes[1]] = coord(axes[1], line_index % size[axes[1]]);
+
+        let hash = self.lattice_hash();
+        let mut grid = Vec3::new(f64::NAN, f64::NAN, f64::NAN);
+        let mut squashed = grid;
+        for (c, sample) in line.into_iter().enumerate() {
+            point[axes[2]] = coord(axes[2], c);
+            let input = Vec3::new(point[0], point[1], point[2]);
+            let stretch = input + (OpenSimplexNoise3D::STRETCH_POINT * input.sum());
+
+            let next = Vec3::new(
+                walk_floor(stretch.x, grid.x),
+                walk_floor(stretch.y, grid.y),
+                walk_floor(stretch.z, grid.z),
+            );
+            if next.x != grid.x || next.y != grid.y || next.z != grid.z {
+                grid = next;
+                squashed = grid + (OpenSimplexNoise3D::SQUISH_POINT * grid.sum());
+            }
+
+            *sample = OpenSimplexNoise3D::eval_in_cell(
+                input,
+                stretch,
+                grid,
+                squashed,
+                hash,
+                self.version,
+            );
+        }
+    }
+}
+
+impl VolumeLayout {
+    // Axes from the slowest to the fastest varying one
+    pub(crate) fn axes(self) -> [usize; 3] {
+        match self {
+            VolumeLayout::XMajor => [0, 1, 2],
+            VolumeLayout::ZMajor => [2, 1, 0],
+        }
+    }
+}
+
+// Moves the floor of the previous value over to `value`, a cell at a time.
+// Large steps would take too many of them, those and the first sample of a row
+// (with a NaN previous floor) get floored directly.
+fn walk_floor(value: f64, previous_floor: f64) -> f64 {
+    let distance = (value - previous_floor).abs();
+    if distance.is_nan() || distance > MAX_WALKED_CELLS {
+        return utils::to_f64(utils::floor(value));
+    }
+
+    let mut floor = previous_floor;
+    while value >= floor + 1.0 {
+        floor += 1.0;
+    }
+    while value < floor {
+        floor -= 1.0;
+    }
+    floor
+}
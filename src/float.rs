@@ -0,0 +1,69 @@
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+use super::math;
+
+// Floating point types the noise can be evaluated with. The constants of the noise are
+// defined as f64 and converted with `from_f64`, which for f64 itself is a no-op.
+pub trait Float:
+    Copy
+    + Default
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+{
+    fn from_f64(value: f64) -> Self;
+    fn from_i64(value: i64) -> Self;
+    fn to_i64(self) -> i64;
+    fn floor(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+}
+
+impl Float for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn from_i64(value: i64) -> Self {
+        value as f64
+    }
+
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+
+    fn floor(self) -> Self {
+        math::floor(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        math::powi(self, n)
+    }
+}
+
+impl Float for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn from_i64(value: i64) -> Self {
+        value as f32
+    }
+
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+
+    fn floor(self) -> Self {
+        math::floorf(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        math::powif(self, n)
+    }
+}
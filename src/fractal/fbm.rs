@@ -0,0 +1,90 @@
+use super::{
+    octaves, sample_2d, sample_3d, sample_4d, DEFAULT_FREQUENCY, DEFAULT_LACUNARITY,
+    DEFAULT_OCTAVES, DEFAULT_SEED_OFFSET,
+};
+use crate::hash::TableHash;
+use crate::{OpenSimplexGenerator, PointHash};
+
+const DEFAULT_PERSISTENCE: f64 = 0.5;
+
+pub struct Fbm<H = TableHash> {
+    noise: OpenSimplexGenerator<H>,
+    octaves: u32,
+    frequency: f64,
+    lacunarity: f64,
+    persistence: f64,
+    seed_offset: f64,
+}
+
+impl<H: PointHash> Fbm<H> {
+    pub fn new(noise: OpenSimplexGenerator<H>) -> Self {
+        Self {
+            noise,
+            octaves: DEFAULT_OCTAVES,
+            frequency: DEFAULT_FREQUENCY,
+            lacunarity: DEFAULT_LACUNARITY,
+            persistence: DEFAULT_PERSISTENCE,
+            seed_offset: DEFAULT_SEED_OFFSET,
+        }
+    }
+
+    pub fn with_octaves(mut self, octaves: u32) -> Self {
+        assert!(octaves > 0, "fBm needs at least one octave");
+        self.octaves = octaves;
+        self
+    }
+
+    pub fn with_frequency(mut self, frequency: f64) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    pub fn with_lacunarity(mut self, lacunarity: f64) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    pub fn with_persistence(mut self, persistence: f64) -> Self {
+        self.persistence = persistence;
+        self
+    }
+
+    // Every octave samples the noise shifted by `octave * seed_offset` on each axis,
+    // so the octaves don't line up with each other around the origin.
+    pub fn with_seed_offset(mut self, seed_offset: f64) -> Self {
+        self.seed_offset = seed_offset;
+        self
+    }
+
+    pub fn eval_2d(&self, x: f64, y: f64) -> f64 {
+        self.sum_octaves(sample_2d(&self.noise, x, y))
+    }
+
+    pub fn eval_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        self.sum_octaves(sample_3d(&self.noise, x, y, z))
+    }
+
+    pub fn eval_4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64 {
+        self.sum_octaves(sample_4d(&self.noise, x, y, z, w))
+    }
+
+    fn sum_octaves(&self, sample: impl Fn(f64, f64) -> f64) -> f64 {
+        let mut value = 0.0;
+        let mut amplitude = 1.0;
+        let mut amplitude_sum = 0.0;
+
+        for (frequency, offset) in octaves(
+            self.octaves,
+            self.frequency,
+            self.lacunarity,
+            self.seed_offset,
+        ) {
+            value += sample(frequency, offset) * amplitude;
+            amplitude_sum += amplitude;
+            amplitude *= self.persistence;
+        }
+
+        // Dividing by the sum of all amplitudes brings the value back into (-1, 1)
+        value / amplitude_sum
+    }
+}
@@ -0,0 +1,68 @@
+mod billow;
+mod fbm;
+mod ridged;
+
+pub use billow::Billow;
+pub use fbm::Fbm;
+pub use ridged::RidgedMulti;
+
+use crate::{OpenSimplexGenerator, PointHash};
+
+const DEFAULT_OCTAVES: u32 = 4;
+const DEFAULT_FREQUENCY: f64 = 1.0;
+const DEFAULT_LACUNARITY: f64 = 2.0;
+const DEFAULT_SEED_OFFSET: f64 = 0.0;
+
+// Yields (frequency, seed offset) for every octave.
+fn octaves(
+    count: u32,
+    frequency: f64,
+    lacunarity: f64,
+    seed_offset: f64,
+) -> impl Iterator<Item = (f64, f64)> {
+    (0..count).scan(frequency, move |frequency, octave| {
+        let current = *frequency;
+        *frequency *= lacunarity;
+        Some((current, octave as f64 * seed_offset))
+    })
+}
+
+fn sample_2d<H: PointHash>(
+    noise: &OpenSimplexGenerator<H>,
+    x: f64,
+    y: f64,
+) -> impl Fn(f64, f64) -> f64 + '_ {
+    move |frequency, offset| noise.eval_2d(x * frequency + offset, y * frequency + offset)
+}
+
+fn sample_3d<H: PointHash>(
+    noise: &OpenSimplexGenerator<H>,
+    x: f64,
+    y: f64,
+    z: f64,
+) -> impl Fn(f64, f64) -> f64 + '_ {
+    move |frequency, offset| {
+        noise.eval_3d(
+            x * frequency + offset,
+            y * frequency + offset,
+            z * frequency + offset,
+        )
+    }
+}
+
+fn sample_4d<H: PointHash>(
+    noise: &OpenSimplexGenerator<H>,
+    x: f64,
+    y: f64,
+    z: f64,
+    w: f64,
+) -> impl Fn(f64, f64) -> f64 + '_ {
+    move |frequency, offset| {
+        noise.eval_4d(
+            x * frequency + offset,
+            y * frequency + offset,
+            z * frequency + offset,
+            w * frequency + offset,
+        )
+    }
+}
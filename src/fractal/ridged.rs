@@ -0,0 +1,122 @@
+use super::{
+    octaves, sample_2d, sample_3d, sample_4d, DEFAULT_FREQUENCY, DEFAULT_LACUNARITY,
+    DEFAULT_OCTAVES, DEFAULT_SEED_OFFSET,
+};
+use crate::math;
+use crate::hash::TableHash;
+use crate::{OpenSimplexGenerator, PointHash};
+
+// Defaults suggested by Musgrave in "Texturing and Modeling: A Procedural Approach"
+const DEFAULT_FRACTAL_INCREMENT: f64 = 1.0;
+const DEFAULT_OFFSET: f64 = 1.0;
+const DEFAULT_GAIN: f64 = 2.0;
+
+pub struct RidgedMulti<H = TableHash> {
+    noise: OpenSimplexGenerator<H>,
+    octaves: u32,
+    frequency: f64,
+    lacunarity: f64,
+    fractal_increment: f64,
+    offset: f64,
+    gain: f64,
+    seed_offset: f64,
+}
+
+impl<H: PointHash> RidgedMulti<H> {
+    pub fn new(noise: OpenSimplexGenerator<H>) -> Self {
+        Self {
+            noise,
+            octaves: DEFAULT_OCTAVES,
+            frequency: DEFAULT_FREQUENCY,
+            lacunarity: DEFAULT_LACUNARITY,
+            fractal_increment: DEFAULT_FRACTAL_INCREMENT,
+            offset: DEFAULT_OFFSET,
+            gain: DEFAULT_GAIN,
+            seed_offset: DEFAULT_SEED_OFFSET,
+        }
+    }
+
+    pub fn with_octaves(mut self, octaves: u32) -> Self {
+        assert!(octaves > 0, "ridged multifractal needs at least one octave");
+        self.octaves = octaves;
+        self
+    }
+
+    pub fn with_frequency(mut self, frequency: f64) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    pub fn with_lacunarity(mut self, lacunarity: f64) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    // Musgrave's `H`: every octave is weighted by `frequency ^ -H`, higher values give smoother results.
+    pub fn with_fractal_increment(mut self, fractal_increment: f64) -> Self {
+        self.fractal_increment = fractal_increment;
+        self
+    }
+
+    // Height of the ridges, the signal of each octave is `(offset - |noise|)^2`. Below 0.5 the
+    // valleys would outgrow the ridges and the output would leave (-1, 1).
+    pub fn with_offset(mut self, offset: f64) -> Self {
+        assert!(
+            offset >= 0.5,
+            "ridged multifractal needs an offset of at least 0.5"
+        );
+        self.offset = offset;
+        self
+    }
+
+    // How strongly the previous octave weights the next one, higher values give sharper ridges.
+    pub fn with_gain(mut self, gain: f64) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    pub fn with_seed_offset(mut self, seed_offset: f64) -> Self {
+        self.seed_offset = seed_offset;
+        self
+    }
+
+    pub fn eval_2d(&self, x: f64, y: f64) -> f64 {
+        self.sum_octaves(sample_2d(&self.noise, x, y))
+    }
+
+    pub fn eval_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        self.sum_octaves(sample_3d(&self.noise, x, y, z))
+    }
+
+    pub fn eval_4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64 {
+        self.sum_octaves(sample_4d(&self.noise, x, y, z, w))
+    }
+
+    fn sum_octaves(&self, sample: impl Fn(f64, f64) -> f64) -> f64 {
+        let mut value = 0.0;
+        let mut max_value = 0.0;
+        let mut weight = 1.0;
+
+        for (frequency, offset) in octaves(
+            self.octaves,
+            self.frequency,
+            self.lacunarity,
+            self.seed_offset,
+        ) {
+            let spectral_weight = math::powf(frequency, -self.fractal_increment);
+
+            let mut signal = self.offset - sample(frequency, offset).abs();
+            signal *= signal;
+            // Ridges of the previous octave decide how much detail this one adds
+            signal *= weight;
+
+            value += signal * spectral_weight;
+            max_value += self.offset * self.offset * spectral_weight;
+
+            weight = (signal * self.gain).clamp(0.0, 1.0);
+        }
+
+        // Signal is always positive, so map [0, max_value] onto (-1, 1)
+        value / max_value * 2.0 - 1.0
+    }
+}
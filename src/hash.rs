@@ -0,0 +1,294 @@
+use super::float::Float;
+use super::open_simplex_noise_3d::grad_index_3d;
+use super::vector::{vec1::Vec1, vec2::Vec2, vec3::Vec3, vec4::Vec4, vecn::VecN};
+use super::PSIZE;
+
+const LEGACY_MASK: i64 = 0xFF;
+const FULL_TABLE_MASK: i64 = 0x7FF;
+const HASHED_MASK: i64 = 0xFFFF;
+const UNBOUNDED_MASK: i64 = -1;
+
+pub trait LatticeHash<T> {
+    fn hash(&self, grid: T) -> i64;
+
+    // Number of lattice cells after which the hashes repeat along each axis, `None` if they
+    // never do.
+    fn repeats_after(&self) -> Option<u32> {
+        None
+    }
+
+    // Index into the gradient table of the built-in evaluator for `T`. Hashes backed by
+    // a permutation table override it for 3D with a lookup into precomputed indices.
+    #[inline]
+    fn gradient_index(&self, grid: T) -> usize
+    where
+        T: GradientIndex,
+    {
+        T::from_hash(self.hash(grid))
+    }
+}
+
+// Lets generators hand out their hash by reference.
+impl<T, H: LatticeHash<T>> LatticeHash<T> for &H {
+    #[inline]
+    fn hash(&self, grid: T) -> i64 {
+        (*self).hash(grid)
+    }
+
+    fn repeats_after(&self) -> Option<u32> {
+        (*self).repeats_after()
+    }
+
+    #[inline]
+    fn gradient_index(&self, grid: T) -> usize
+    where
+        T: GradientIndex,
+    {
+        (*self).gradient_index(grid)
+    }
+}
+
+// Lattice points with a built-in gradient table, maps their hashes to its indices. Not
+// exported, so only the hashes of this crate can override `LatticeHash::gradient_index`.
+pub trait GradientIndex {
+    fn from_hash(hash: i64) -> usize;
+}
+
+// Shuffled `0..PSIZE`, along with every entry already mapped to a 3D gradient index, which
+// saves a modulo by 24 per lattice point. Only 3D is precomputed: the 2D and 4D indices are a
+// mask and a shift of the entry, and measured slower as lookups.
+#[derive(Clone)]
+pub struct PermTable {
+    pub(crate) perm: [u16; PSIZE as usize],
+    grad_index_3d: [u8; PSIZE as usize],
+}
+
+impl PermTable {
+    pub(crate) const fn new(perm: [u16; PSIZE as usize]) -> Self {
+        let mut grad_index_3d_table = [0; PSIZE as usize];
+        let mut i = 0;
+        while i < PSIZE as usize {
+            grad_index_3d_table[i] = grad_index_3d(perm[i] as i64) as u8;
+            i += 1;
+        }
+
+        Self {
+            perm,
+            grad_index_3d: grad_index_3d_table,
+        }
+    }
+}
+
+// Number of lattice cells after which the gradients repeat along each axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum HashPeriod {
+    // Only the first 256 entries of the permutation table are used, as in the 0.3 releases
+    #[default]
+    Cells256,
+    // The whole permutation table
+    Cells2048,
+    // Lattice coordinates are hashed together with the seed instead of looked up in the table
+    Cells65536,
+    // Hashed as well, but without wrapping the coordinates first
+    Unbounded,
+}
+
+impl HashPeriod {
+    // The period in cells, `None` for `Unbounded`.
+    pub(crate) const fn cells(self) -> Option<u32> {
+        match self {
+            HashPeriod::Cells256 => Some(256),
+            HashPeriod::Cells2048 => Some(2048),
+            HashPeriod::Cells65536 => Some(65536),
+            HashPeriod::Unbounded => None,
+        }
+    }
+}
+
+// Lattice hash of an `OpenSimplexNoise`, following its `HashPeriod`.
+#[derive(Clone)]
+pub struct TableHash {
+    pub(crate) perm: PermTable,
+    pub(crate) seed: i64,
+    pub(crate) period: HashPeriod,
+}
+
+impl TableHash {
+    // Entry of the permutation table the coordinates end up on, for the periods backed by it.
+    // Called for every contributing lattice point, inlining it keeps the default period as fast
+    // as hashing with the bare table.
+    #[inline]
+    fn slot(&self, coordinates: &[i64]) -> Option<usize> {
+        match self.period {
+            HashPeriod::Cells256 => Some(slot(&self.perm.perm, coordinates, LEGACY_MASK)),
+            HashPeriod::Cells2048 => Some(slot(&self.perm.perm, coordinates, FULL_TABLE_MASK)),
+            HashPeriod::Cells65536 | HashPeriod::Unbounded => None,
+        }
+    }
+
+    fn mix(&self, coordinates: &[i64]) -> i64 {
+        let mask = match self.period {
+            HashPeriod::Cells65536 => HASHED_MASK,
+            _ => UNBOUNDED_MASK,
+        };
+        mix(self.seed, coordinates, mask)
+    }
+
+    #[inline]
+    fn hash_coordinates(&self, coordinates: &[i64]) -> i64 {
+        match self.slot(coordinates) {
+            Some(slot) => i64::from(self.perm.perm[slot]),
+            None => self.mix(coordinates),
+        }
+    }
+}
+
+impl<F: Float> LatticeHash<Vec1<F>> for TableHash {
+    #[inline]
+    fn hash(&self, grid: Vec1<F>) -> i64 {
+        self.hash_coordinates(&coordinates_1d(grid))
+    }
+
+    fn repeats_after(&self) -> Option<u32> {
+        self.period.cells()
+    }
+}
+
+impl<F: Float> LatticeHash<Vec2<F>> for TableHash {
+    #[inline]
+    fn hash(&self, grid: Vec2<F>) -> i64 {
+        self.hash_coordinates(&coordinates_2d(grid))
+    }
+
+    fn repeats_after(&self) -> Option<u32> {
+        self.period.cells()
+    }
+}
+
+impl<F: Float> LatticeHash<Vec3<F>> for TableHash {
+    #[inline]
+    fn hash(&self, grid: Vec3<F>) -> i64 {
+        self.hash_coordinates(&coordinates_3d(grid))
+    }
+
+    fn repeats_after(&self) -> Option<u32> {
+        self.period.cells()
+    }
+
+    fn gradient_index(&self, grid: Vec3<F>) -> usize
+    where
+        Vec3<F>: GradientIndex,
+    {
+        let coordinates = coordinates_3d(grid);
+        match self.slot(&coordinates) {
+            Some(slot) => usize::from(self.perm.grad_index_3d[slot]),
+            None => grad_index_3d(self.mix(&coordinates)),
+        }
+    }
+}
+
+impl<F: Float> LatticeHash<Vec4<F>> for TableHash {
+    #[inline]
+    fn hash(&self, grid: Vec4<F>) -> i64 {
+        self.hash_coordinates(&coordinates_4d(grid))
+    }
+
+    fn repeats_after(&self) -> Option<u32> {
+        self.period.cells()
+    }
+}
+
+impl<F: Float, const N: usize> LatticeHash<VecN<F, N>> for TableHash {
+    #[inline]
+    fn hash(&self, grid: VecN<F, N>) -> i64 {
+        self.hash_coordinates(&coordinates_nd(grid))
+    }
+
+    fn repeats_after(&self) -> Option<u32> {
+        self.period.cells()
+    }
+}
+
+// Hashes the lattice coordinates together with a seed, like `HashPeriod::Unbounded`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SeedHash {
+    pub(crate) seed: i64,
+}
+
+impl<F: Float> LatticeHash<Vec1<F>> for SeedHash {
+    fn hash(&self, grid: Vec1<F>) -> i64 {
+        mix(self.seed, &coordinates_1d(grid), UNBOUNDED_MASK)
+    }
+}
+
+impl<F: Float> LatticeHash<Vec2<F>> for SeedHash {
+    fn hash(&self, grid: Vec2<F>) -> i64 {
+        mix(self.seed, &coordinates_2d(grid), UNBOUNDED_MASK)
+    }
+}
+
+impl<F: Float> LatticeHash<Vec3<F>> for SeedHash {
+    fn hash(&self, grid: Vec3<F>) -> i64 {
+        mix(self.seed, &coordinates_3d(grid), UNBOUNDED_MASK)
+    }
+}
+
+impl<F: Float> LatticeHash<Vec4<F>> for SeedHash {
+    fn hash(&self, grid: Vec4<F>) -> i64 {
+        mix(self.seed, &coordinates_4d(grid), UNBOUNDED_MASK)
+    }
+}
+
+impl<F: Float, const N: usize> LatticeHash<VecN<F, N>> for SeedHash {
+    fn hash(&self, grid: VecN<F, N>) -> i64 {
+        mix(self.seed, &coordinates_nd(grid), UNBOUNDED_MASK)
+    }
+}
+
+fn coordinates_1d<F: Float>(grid: Vec1<F>) -> [i64; 1] {
+    [grid.x.to_i64()]
+}
+
+fn coordinates_2d<F: Float>(grid: Vec2<F>) -> [i64; 2] {
+    [grid.x.to_i64(), grid.y.to_i64()]
+}
+
+fn coordinates_3d<F: Float>(grid: Vec3<F>) -> [i64; 3] {
+    [grid.x.to_i64(), grid.y.to_i64(), grid.z.to_i64()]
+}
+
+fn coordinates_4d<F: Float>(grid: Vec4<F>) -> [i64; 4] {
+    [
+        grid.x.to_i64(),
+        grid.y.to_i64(),
+        grid.z.to_i64(),
+        grid.w.to_i64(),
+    ]
+}
+
+fn coordinates_nd<F: Float, const N: usize>(grid: VecN<F, N>) -> [i64; N] {
+    grid.coords.map(F::to_i64)
+}
+
+// Every coordinate offsets the entry picked by the previous ones, all wrapped by `mask`.
+// Returns the index of the last entry, whose value is the hash.
+fn slot(perm: &[u16; PSIZE as usize], coordinates: &[i64], mask: i64) -> usize {
+    coordinates[1..]
+        .iter()
+        .fold((coordinates[0] & mask) as usize, |index, coordinate| {
+            ((i64::from(perm[index]) + coordinate) & mask) as usize
+        })
+}
+
+// Multiplicative mixing of every coordinate into the seed, followed by the SplitMix64 finalizer.
+fn mix(seed: i64, coordinates: &[i64], mask: i64) -> i64 {
+    let mut hash = seed as u64;
+    for coordinate in coordinates {
+        hash = (hash ^ (coordinate & mask) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        hash ^= hash >> 32;
+    }
+
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    ((hash ^ (hash >> 31)) >> 32) as i64
+}
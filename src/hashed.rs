@@ -0,0 +1,25 @@
+use super::hash::SeedHash;
+use super::{NoiseVersion, OpenSimplexGenerator, DEFAULT_SEED};
+
+// OpenSimplex noise without a permutation table, the gradient of every lattice point comes from
+// hashing its coordinates with the seed. Construction is free, so it suits one generator per
+// entity. The output matches `OpenSimplexNoise` with `HashPeriod::Unbounded` and the same seed.
+pub type HashedOpenSimplexNoise = OpenSimplexGenerator<SeedHash>;
+
+impl HashedOpenSimplexNoise {
+    pub const fn new(custom_seed: Option<i64>) -> Self {
+        Self::with_version(custom_seed, NoiseVersion::DEFAULT)
+    }
+
+    pub const fn with_version(custom_seed: Option<i64>, version: NoiseVersion) -> Self {
+        let seed = match custom_seed {
+            Some(value) => value,
+            None => DEFAULT_SEED,
+        };
+
+        Self {
+            hash: SeedHash { seed },
+            version,
+        }
+    }
+}
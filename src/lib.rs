@@ -1,70 +1,322 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("no_std builds need the `libm` feature for their float functions");
+
+mod batch;
+mod curl;
+mod fill;
+mod float;
+mod fractal;
+mod hash;
+mod hashed;
+mod looping;
+mod math;
+mod open_simplex_2;
+mod open_simplex_noise_1d;
 mod open_simplex_noise_2d;
 mod open_simplex_noise_3d;
 mod open_simplex_noise_4d;
+mod open_simplex_noise_nd;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod periodic;
+mod point;
+mod seed;
+mod sphere;
 mod utils;
 mod vector;
+mod version;
 
-use open_simplex_noise_2d::OpenSimplexNoise2D;
-use open_simplex_noise_3d::OpenSimplexNoise3D;
-use open_simplex_noise_4d::OpenSimplexNoise4D;
-use vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4};
+pub use fill::VolumeLayout;
+pub use float::Float;
+pub use fractal::{Billow, Fbm, RidgedMulti};
+pub use hash::{HashPeriod, LatticeHash, SeedHash};
+use hash::{PermTable, TableHash};
+pub use hashed::HashedOpenSimplexNoise;
+pub use open_simplex_2::{OpenSimplex2F, OpenSimplex2S};
+pub use open_simplex_noise_1d::OpenSimplexNoise1D;
+pub use open_simplex_noise_2d::OpenSimplexNoise2D;
+pub use open_simplex_noise_3d::OpenSimplexNoise3D;
+pub use open_simplex_noise_4d::OpenSimplexNoise4D;
+pub use open_simplex_noise_nd::OpenSimplexNoiseND;
+pub use point::{NoisePoint, PointHash};
+pub use seed::SeedAlgorithm;
+pub use sphere::{CubeFace, Sphere, SpherePoint};
+pub use utils::{Contribution, ValueWithGradient};
+pub use vector::{vec1::Vec1, vec2::Vec2, vec3::Vec3, vec4::Vec4, vecn::VecN, VecMethods, VecType};
+pub use version::NoiseVersion;
 
 pub const PSIZE: i64 = 2048;
 const DEFAULT_SEED: i64 = 0;
 
-type PermTable = [i64; PSIZE as usize];
-
-pub struct OpenSimplexNoise {
-    perm: PermTable,
+// OpenSimplex noise over the lattice hash `H`, which picks the gradient of every lattice point.
+// `OpenSimplexNoise` and `HashedOpenSimplexNoise` are its two flavours and share every method
+// that only needs the hash, fills, batches and the other helpers included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpenSimplexGenerator<H> {
+    hash: H,
+    version: NoiseVersion,
 }
 
+// OpenSimplex noise with its gradients looked up in a permutation table shuffled from the seed.
+pub type OpenSimplexNoise = OpenSimplexGenerator<TableHash>;
+
 impl OpenSimplexNoise {
-    pub fn new(custom_seed: Option<i64>) -> Self {
+    pub const fn new(custom_seed: Option<i64>) -> Self {
+        Self::with_version(custom_seed, NoiseVersion::DEFAULT)
+    }
+
+    // Shorthand for `new(Some(seed))`. Like the other constructors it's a const fn, so
+    // generators with fixed seeds can be built into statics at compile time.
+    pub const fn from_seed_const(seed: i64) -> Self {
+        Self::new(Some(seed))
+    }
+
+    // Pinning the version keeps the output bit-identical across crate upgrades.
+    pub const fn with_version(custom_seed: Option<i64>, version: NoiseVersion) -> Self {
+        Self::build(custom_seed, version.seed_algorithm(), version)
+    }
+
+    // Shuffles the permutation table with the given algorithm, but evaluates with the default version.
+    pub const fn with_seed_algorithm(custom_seed: Option<i64>, algorithm: SeedAlgorithm) -> Self {
+        Self::build(custom_seed, algorithm, NoiseVersion::DEFAULT)
+    }
+
+    const fn build(
+        custom_seed: Option<i64>,
+        algorithm: SeedAlgorithm,
+        version: NoiseVersion,
+    ) -> Self {
         let seed = match custom_seed {
             Some(value) => value,
             None => DEFAULT_SEED,
         };
 
         Self {
-            perm: generate_perm_array(seed),
+            hash: TableHash {
+                perm: algorithm.generate_perm_array(seed),
+                seed,
+                period: HashPeriod::Cells256,
+            },
+            version,
         }
     }
 
+    // Any period but the default `Cells256` changes the output, large worlds should pick a longer
+    // one to avoid visible repetition.
+    pub const fn with_hash_period(mut self, hash_period: HashPeriod) -> Self {
+        self.hash.period = hash_period;
+        self
+    }
+
+    pub fn hash_period(&self) -> HashPeriod {
+        self.hash.period
+    }
+}
+
+impl<H: PointHash> OpenSimplexGenerator<H> {
+    pub fn version(&self) -> NoiseVersion {
+        self.version
+    }
+
+    // Lattice hash of this generator, for evaluating custom `NoiseEvaluator`s with its seed.
+    pub fn lattice_hash(&self) -> &H {
+        &self.hash
+    }
+
+    // Evaluates a point of any `NoisePoint` type, e.g. `[x, y, z]`, so code generic over the
+    // dimension needs no per-dimension methods. The methods below are shorthands for it.
+    pub fn eval<P: NoisePoint>(&self, point: P) -> f64 {
+        point.eval(&self.hash, self.version)
+    }
+
+    pub fn eval_with_gradient<P: NoisePoint>(&self, point: P) -> (f64, P::Gradient) {
+        point.eval_with_gradient(&self.hash, self.version)
+    }
+
+    pub fn eval_1d(&self, x: f64) -> f64 {
+        self.eval(x)
+    }
+
     pub fn eval_2d(&self, x: f64, y: f64) -> f64 {
-        OpenSimplexNoise2D::eval(Vec2::new(x, y), &self.perm)
+        self.eval([x, y])
     }
 
     pub fn eval_3d(&self, x: f64, y: f64, z: f64) -> f64 {
-        OpenSimplexNoise3D::eval(Vec3::new(x, y, z), &self.perm)
+        self.eval([x, y, z])
     }
 
     pub fn eval_4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64 {
-        OpenSimplexNoise4D::eval(Vec4::new(x, y, z, w), &self.perm)
+        self.eval([x, y, z, w])
+    }
+
+    pub fn eval_5d(&self, x: f64, y: f64, z: f64, w: f64, v: f64) -> f64 {
+        self.eval([x, y, z, w, v])
+    }
+
+    pub fn eval_6d(&self, x: f64, y: f64, z: f64, w: f64, v: f64, u: f64) -> f64 {
+        self.eval([x, y, z, w, v, u])
+    }
+
+    // Noise of any dimension from 2 to 6. It doesn't match `eval_2d` to `eval_4d`, which keep
+    // their own gradients.
+    pub fn eval_nd<const N: usize>(&self, point: [f64; N]) -> f64
+    where
+        H: LatticeHash<VecN<f64, N>>,
+    {
+        OpenSimplexNoiseND::eval(VecN::new(point), &self.hash, self.version)
     }
-}
 
-pub trait NoiseEvaluator<T: vector::VecType<f64>> {
-    const STRETCH_POINT: T;
-    const SQUISH_POINT: T;
+    // The derivative of `eval_1d` along with its value.
+    pub fn eval_1d_with_gradient(&self, x: f64) -> (f64, f64) {
+        self.eval_with_gradient(x)
+    }
 
-    fn eval(point: T, perm: &PermTable) -> f64;
-    fn extrapolate(grid: T, delta: T, perm: &PermTable) -> f64;
+    pub fn eval_2d_with_gradient(&self, x: f64, y: f64) -> (f64, [f64; 2]) {
+        self.eval_with_gradient([x, y])
+    }
+
+    pub fn eval_3d_with_gradient(&self, x: f64, y: f64, z: f64) -> (f64, [f64; 3]) {
+        self.eval_with_gradient([x, y, z])
+    }
+
+    pub fn eval_4d_with_gradient(&self, x: f64, y: f64, z: f64, w: f64) -> (f64, [f64; 4]) {
+        self.eval_with_gradient([x, y, z, w])
+    }
+
+    pub fn eval_nd_with_gradient<const N: usize>(&self, point: [f64; N]) -> (f64, [f64; N])
+    where
+        H: LatticeHash<VecN<f64, N>>,
+    {
+        let (value, gradient) =
+            OpenSimplexNoiseND::eval_with_gradient(VecN::new(point), &self.hash, self.version);
+        (value, gradient.coords)
+    }
+
+    // Single precision variants, computed in f32 all the way through. They follow the f64
+    // output closely, but don't reproduce it bit for bit.
+    pub fn eval_1d_f32(&self, x: f32) -> f32
+    where
+        H: LatticeHash<Vec1<f32>>,
+    {
+        OpenSimplexNoise1D::eval(Vec1::new(x), &self.hash, self.version)
+    }
+
+    pub fn eval_2d_f32(&self, x: f32, y: f32) -> f32
+    where
+        H: LatticeHash<Vec2<f32>>,
+    {
+        OpenSimplexNoise2D::eval(Vec2::new(x, y), &self.hash, self.version)
+    }
+
+    pub fn eval_3d_f32(&self, x: f32, y: f32, z: f32) -> f32
+    where
+        H: LatticeHash<Vec3<f32>>,
+    {
+        OpenSimplexNoise3D::eval(Vec3::new(x, y, z), &self.hash, self.version)
+    }
+
+    pub fn eval_4d_f32(&self, x: f32, y: f32, z: f32, w: f32) -> f32
+    where
+        H: LatticeHash<Vec4<f32>>,
+    {
+        OpenSimplexNoise4D::eval(Vec4::new(x, y, z, w), &self.hash, self.version)
+    }
+
+    pub fn eval_1d_with_gradient_f32(&self, x: f32) -> (f32, f32)
+    where
+        H: LatticeHash<Vec1<f32>>,
+    {
+        let (value, gradient) =
+            OpenSimplexNoise1D::eval_with_gradient(Vec1::new(x), &self.hash, self.version);
+        (value, gradient.x)
+    }
+
+    pub fn eval_2d_with_gradient_f32(&self, x: f32, y: f32) -> (f32, [f32; 2])
+    where
+        H: LatticeHash<Vec2<f32>>,
+    {
+        let (value, gradient) =
+            OpenSimplexNoise2D::eval_with_gradient(Vec2::new(x, y), &self.hash, self.version);
+        (value, [gradient.x, gradient.y])
+    }
+
+    pub fn eval_3d_with_gradient_f32(&self, x: f32, y: f32, z: f32) -> (f32, [f32; 3])
+    where
+        H: LatticeHash<Vec3<f32>>,
+    {
+        let (value, gradient) =
+            OpenSimplexNoise3D::eval_with_gradient(Vec3::new(x, y, z), &self.hash, self.version);
+        (value, [gradient.x, gradient.y, gradient.z])
+    }
+
+    pub fn eval_4d_with_gradient_f32(&self, x: f32, y: f32, z: f32, w: f32) -> (f32, [f32; 4])
+    where
+        H: LatticeHash<Vec4<f32>>,
+    {
+        let (value, gradient) =
+            OpenSimplexNoise4D::eval_with_gradient(Vec4::new(x, y, z, w), &self.hash, self.version);
+        (value, [gradient.x, gradient.y, gradient.z, gradient.w])
+    }
 }
 
-fn generate_perm_array(seed: i64) -> PermTable {
-    let mut perm: PermTable = [0; PSIZE as usize];
+// Noise over the lattice points of a given dimension. Implementors choose the gradient of every
+// lattice point, how it is extrapolated and how it fades with the distance, `contribute` turns
+// those into the contributions summed by `OpenSimplexNoise2D::evaluate` and its 3D / 4D
+// counterparts.
+pub trait NoiseEvaluator<T: VecType<F>, F: Float = f64> {
+    fn stretch_point() -> T;
+    fn squish_point() -> T;
 
-    let mut source: Vec<i64> = (0..PSIZE).map(|x| x).collect();
+    fn eval<H: LatticeHash<T>>(point: T, perm: &H, version: NoiseVersion) -> F;
+    fn eval_with_gradient<H: LatticeHash<T>>(point: T, perm: &H, version: NoiseVersion) -> (F, T);
+    fn extrapolate<H: LatticeHash<T>>(grid: T, delta: T, perm: &H) -> F;
+    fn get_grad<H: LatticeHash<T>>(grid: T, perm: &H) -> T;
 
-    let seed: i128 = (seed as i128 * 6_364_136_223_846_793_005) + 1_442_695_040_888_963_407;
-    for i in (0..PSIZE).rev() {
-        let mut r = ((seed + 31) % (i as i128 + 1)) as i64;
-        if r < 0 {
-            r += i + 1;
+    // Weight of a lattice point at `delta` from the evaluated point, (2 - |delta|^2)^4 by default.
+    // The lattice walks skip the points with |delta|^2 >= 2, kernels have to vanish there.
+    fn kernel(delta: T) -> F {
+        let attn = F::from_f64(2.0) - delta.get_attenuation_factor();
+        if attn > F::from_f64(0.0) {
+            return attn.powi(4);
         }
-        perm[i as usize] = source[r as usize];
-        source[r as usize] = source[i as usize];
+
+        F::from_f64(0.0)
+    }
+
+    // Gradient of `kernel` along `delta`
+    fn kernel_gradient(delta: T) -> T {
+        let attn = F::from_f64(2.0) - delta.get_attenuation_factor();
+        if attn > F::from_f64(0.0) {
+            return delta * (F::from_f64(-8.0) * attn.powi(3));
+        }
+
+        delta * F::from_f64(0.0)
+    }
+
+    // Contribution of the lattice point `grid + delta` to the point at `origin` from the
+    // squashed `grid`. Passing it to a lattice walk, e.g.
+    // `OpenSimplexNoise3D::evaluate(point, perm, version, MyEvaluator::contribute)`, evaluates
+    // the noise of `MyEvaluator`.
+    fn contribute<H: LatticeHash<T>>(delta: T, origin: T, grid: T, perm: &H) -> F
+    where
+        Self: Sized,
+    {
+        utils::contribute::<Self, F, T, H>(delta, origin, grid, perm)
     }
 
-    perm
+    // Same as `contribute`, along with the derivative along the evaluated point.
+    fn contribute_with_gradient<H: LatticeHash<T>>(
+        delta: T,
+        origin: T,
+        grid: T,
+        perm: &H,
+    ) -> ValueWithGradient<F, T>
+    where
+        Self: Sized,
+        T: Default,
+    {
+        utils::contribute_with_gradient::<Self, F, T, H>(delta, origin, grid, perm)
+    }
 }
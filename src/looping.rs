@@ -0,0 +1,33 @@
+use core::f64::consts::TAU;
+
+use super::math;
+use super::{OpenSimplexGenerator, PointHash};
+
+// Point on the circle of `radius` around the origin, a full turn every `period`.
+fn circle(position: f64, period: f64, radius: f64) -> (f64, f64) {
+    let (sin, cos) = math::sin_cos(position / period * TAU);
+    (radius * cos, radius * sin)
+}
+
+// Both helpers wrap an axis around a circle in a spare plane of the 4D noise, whose values
+// then repeat seamlessly. Larger radii walk through more of the noise before coming back, a
+// radius of `period * scale / TAU` keeps features the size they would have at `scale`.
+impl<H: PointHash> OpenSimplexGenerator<H> {
+    // 2D noise animated by `t`, which loops every `loop_length`.
+    pub fn eval_2d_looping(&self, x: f64, y: f64, t: f64, loop_length: f64, radius: f64) -> f64 {
+        assert!(loop_length > 0.0, "loop length has to be positive");
+
+        let (z, w) = circle(t, loop_length, radius);
+        self.eval_4d(x, y, z, w)
+    }
+
+    // 2D noise tiling every `period` units along each axis, without the shear of
+    // `eval_2d_periodic` and with periods that don't have to be whole numbers.
+    pub fn eval_2d_torus(&self, x: f64, y: f64, period: [f64; 2], radius: [f64; 2]) -> f64 {
+        assert!(period.iter().all(|&p| p > 0.0), "period has to be positive");
+
+        let (x0, x1) = circle(x, period[0], radius[0]);
+        let (y0, y1) = circle(y, period[1], radius[1]);
+        self.eval_4d(x0, x1, y0, y1)
+    }
+}
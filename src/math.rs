@@ -0,0 +1,120 @@
+// Float functions missing from core, taken from std when it's available and from libm
+// otherwise. Both round the same way, apart from `powf` and `sin_cos` which may differ in the
+// last bit.
+
+#[cfg(feature = "std")]
+pub(crate) fn floor(x: f64) -> f64 {
+    x.floor()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn floor(x: f64) -> f64 {
+    libm::floor(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn floorf(x: f32) -> f32 {
+    x.floor()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn floorf(x: f32) -> f32 {
+    libm::floorf(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn round(x: f64) -> f64 {
+    x.round()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn round(x: f64) -> f64 {
+    libm::round(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    x.powf(n)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    libm::pow(x, n)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn powi(x: f64, n: i32) -> f64 {
+    x.powi(n)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn powi(x: f64, n: i32) -> f64 {
+    exponentiate(x, n, 1.0)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn powif(x: f32, n: i32) -> f32 {
+    x.powi(n)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn powif(x: f32, n: i32) -> f32 {
+    exponentiate(x, n, 1.0)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    x.sin_cos()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    libm::sincos(x)
+}
+
+// Squares and multiplies in the same order as the compiler runtime behind `powi`, so the
+// results are identical.
+#[cfg(not(feature = "std"))]
+fn exponentiate<F>(mut x: F, n: i32, one: F) -> F
+where
+    F: Copy + core::ops::Mul<Output = F> + core::ops::Div<Output = F>,
+{
+    let mut exponent = n.unsigned_abs();
+    let mut result = one;
+    loop {
+        if exponent & 1 == 1 {
+            result = result * x;
+        }
+        exponent /= 2;
+        if exponent == 0 {
+            break;
+        }
+        x = x * x;
+    }
+
+    if n < 0 {
+        one / result
+    } else {
+        result
+    }
+}
+
+// Same as `f64::rem_euclid`, which only std provides.
+pub(crate) fn rem_euclid(x: f64, rhs: f64) -> f64 {
+    let r = x % rhs;
+    if r < 0.0 {
+        r + rhs.abs()
+    } else {
+        r
+    }
+}
@@ -0,0 +1,158 @@
+use super::super::math;
+use super::super::utils;
+use super::super::vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4, VecMethods};
+use super::super::{PermTable, SeedAlgorithm, DEFAULT_SEED};
+
+const SKEW_2D: f64 = 0.366_025_403_784_439; // (sqrt(2 + 1) - 1) / 2
+const UNSKEW_2D: f64 = -0.211_324_865_405_187; // (1 / sqrt(2 + 1) - 1) / 2
+const SKEW_4D: f64 = -0.138_196_601_125_011; // (1 / sqrt(4 + 1) - 1) / 4
+const UNSKEW_4D: f64 = 0.309_016_994_374_947; // (sqrt(4 + 1) - 1) / 4
+
+// Small enough that the points the evaluators add are all the points in range: the corners of
+// the triangle in 2D, the closest point of each cubic lattice and its neighbour along the axis
+// of the largest offset in 3D, and the closest point of each lattice copy in 4D.
+const RADIUS_SQUARED_2D: f64 = 0.5;
+const RADIUS_SQUARED_3D: f64 = 0.5;
+const RADIUS_SQUARED_4D: f64 = 0.5;
+
+// Largest values the sums can reach, so the output fits in [-1, 1]. Found by maximising over
+// the input position, with every lattice point in range taking its most aligned gradient.
+const NORMALIZING_SCALAR_2D: f64 = 0.010_016_341_213_657_12;
+const NORMALIZING_SCALAR_3D: f64 = 0.030_485_933_181_313_19;
+const NORMALIZING_SCALAR_4D: f64 = 0.031_876_672_785_452_62;
+
+// Five copies of the 4D lattice, each shifted by this step along the main diagonal.
+const LATTICE_COPIES_4D: usize = 5;
+const LATTICE_STEP_4D: f64 = 0.2;
+
+// OpenSimplex2F, the faster successor of the original OpenSimplex. Every lattice point has
+// a smaller kernel, so fewer of them contribute to each evaluation.
+pub struct OpenSimplex2F {
+    perm: PermTable,
+}
+
+impl OpenSimplex2F {
+    pub fn new(custom_seed: Option<i64>) -> Self {
+        let seed = match custom_seed {
+            Some(value) => value,
+            None => DEFAULT_SEED,
+        };
+
+        Self {
+            perm: SeedAlgorithm::AdvancingLcg.generate_perm_array(seed),
+        }
+    }
+
+    pub fn eval_2d(&self, x: f64, y: f64) -> f64 {
+        let input = Vec2::new(x, y);
+        let skewed = input + Vec2::new(SKEW_2D, SKEW_2D) * input.sum();
+        let grid = skewed.map(utils::floor).map(utils::to_f64);
+        let ins = skewed - grid;
+
+        let contribute = |x: f64, y: f64| {
+            let delta = ins - Vec2::new(x, y);
+            let delta = delta + Vec2::new(UNSKEW_2D, UNSKEW_2D) * delta.sum();
+            super::contribute(
+                delta,
+                super::grad_2d(&self.perm, grid + Vec2::new(x, y)),
+                RADIUS_SQUARED_2D,
+            )
+        };
+
+        // The cell is split along its diagonal into two triangles sharing (0, 0) and (1, 1)
+        let value = if ins.x > ins.y {
+            contribute(1.0, 0.0)
+        } else {
+            contribute(0.0, 1.0)
+        };
+
+        (value + contribute(0.0, 0.0) + contribute(1.0, 1.0)) / NORMALIZING_SCALAR_2D
+    }
+
+    pub fn eval_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        self.eval_3d_rotated(super::rotate_3d(Vec3::new(x, y, z)))
+    }
+
+    pub fn eval_4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64 {
+        let input = Vec4::new(x, y, z, w);
+        let skewed = input + Vec4::new(SKEW_4D, SKEW_4D, SKEW_4D, SKEW_4D) * input.sum();
+        let mut grid = skewed.map(utils::floor).map(utils::to_f64);
+        let mut ins = skewed - grid;
+
+        // Start from the lattice copy which is the most likely to have its closest point
+        // inside the current cell, then walk through the remaining ones.
+        let starting_copy = (ins.sum() * 1.25) as usize;
+        let mut copy = starting_copy;
+        ins = ins - Vec4::new(1.0, 1.0, 1.0, 1.0) * (starting_copy as f64 * LATTICE_STEP_4D);
+
+        let mut value = 0.0;
+        for i in 0..LATTICE_COPIES_4D {
+            // Next point is the closest vertex of the simplex whose base is the previous point
+            let step = Self::closest_vertex_step_4d(ins);
+            grid = grid + step;
+            ins = ins - step;
+
+            let delta = ins + Vec4::new(UNSKEW_4D, UNSKEW_4D, UNSKEW_4D, UNSKEW_4D) * ins.sum();
+            let grad = super::grad_4d(&self.perm, grid, copy as f64);
+            value += super::contribute(delta, grad, RADIUS_SQUARED_4D);
+
+            // Move on to the previous copy, shifted down the main diagonal, which wraps
+            // around to the last copy one cell lower.
+            ins = ins + Vec4::new(1.0, 1.0, 1.0, 1.0) * LATTICE_STEP_4D;
+            if i == starting_copy {
+                grid = grid - Vec4::new(1.0, 1.0, 1.0, 1.0);
+                copy += LATTICE_COPIES_4D;
+            }
+            copy -= 1;
+        }
+
+        value / NORMALIZING_SCALAR_4D
+    }
+
+    // Two interleaved cubic lattices form a body centered cubic lattice, from each of them
+    // only the closest point and the next one along the axis with the largest offset can be
+    // in range.
+    fn eval_3d_rotated(&self, rotated: Vec3<f64>) -> f64 {
+        let mut value = 0.0;
+        for copy in 0..2 {
+            // The second lattice is offset by half a cell on every axis
+            let shifted = rotated + Vec3::new(0.5, 0.5, 0.5) * copy as f64;
+            let grid = shifted.map(math::round);
+            let ins = shifted - grid;
+
+            let contribute = |step: Vec3<f64>| {
+                let grad = super::grad_3d(&self.perm, grid + step, copy as f64);
+                super::contribute(ins - step, grad, RADIUS_SQUARED_3D)
+            };
+
+            let abs = ins.map(f64::abs);
+            let step = if abs.x >= abs.y && abs.x >= abs.z {
+                Vec3::new(ins.x.signum(), 0.0, 0.0)
+            } else if abs.y > abs.x && abs.y >= abs.z {
+                Vec3::new(0.0, ins.y.signum(), 0.0)
+            } else {
+                Vec3::new(0.0, 0.0, ins.z.signum())
+            };
+
+            value += contribute(Vec3::new(0.0, 0.0, 0.0)) + contribute(step);
+        }
+
+        value / NORMALIZING_SCALAR_3D
+    }
+
+    fn closest_vertex_step_4d(ins: Vec4<f64>) -> Vec4<f64> {
+        let base_score = 1.0 - ins.sum();
+        if ins.x >= ins.y && ins.x >= ins.z && ins.x >= ins.w && ins.x >= base_score {
+            Vec4::new(1.0, 0.0, 0.0, 0.0)
+        } else if ins.y > ins.x && ins.y >= ins.z && ins.y >= ins.w && ins.y >= base_score {
+            Vec4::new(0.0, 1.0, 0.0, 0.0)
+        } else if ins.z > ins.x && ins.z > ins.y && ins.z >= ins.w && ins.z >= base_score {
+            Vec4::new(0.0, 0.0, 1.0, 0.0)
+        } else if ins.w > ins.x && ins.w > ins.y && ins.w > ins.z && ins.w >= base_score {
+            Vec4::new(0.0, 0.0, 0.0, 1.0)
+        } else {
+            // The base itself is the closest
+            Vec4::new(0.0, 0.0, 0.0, 0.0)
+        }
+    }
+}
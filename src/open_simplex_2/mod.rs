@@ -0,0 +1,155 @@
+mod fast;
+mod smooth;
+
+pub use fast::OpenSimplex2F;
+pub use smooth::OpenSimplex2S;
+
+use super::math;
+use super::open_simplex_noise_4d::GRAD_TABLE as GRAD_TABLE_4D;
+use super::vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4, VecMethods, VecType};
+use super::{PermTable, PSIZE};
+
+const PMASK: i64 = PSIZE - 1;
+
+const ROTATE_3D: f64 = 2.0 / 3.0;
+const ROOT3_OVER_3: f64 = 0.577_350_269_189_626;
+const ORTHOGONALIZER_3D: f64 = -0.211_324_865_405_187; // (1 / sqrt(2 + 1) - 1) / 2
+
+const GRAD_TABLE_2D: [Vec2<f64>; 24] = [
+    Vec2::new(0.991_444_861_373_81, 0.130_526_192_220_052),
+    Vec2::new(0.923_879_532_511_287, 0.382_683_432_365_09),
+    Vec2::new(0.793_353_340_291_235, 0.608_761_429_008_721),
+    Vec2::new(0.608_761_429_008_721, 0.793_353_340_291_235),
+    Vec2::new(0.382_683_432_365_09, 0.923_879_532_511_287),
+    Vec2::new(0.130_526_192_220_052, 0.991_444_861_373_81),
+    Vec2::new(-0.130_526_192_220_052, 0.991_444_861_373_81),
+    Vec2::new(-0.382_683_432_365_09, 0.923_879_532_511_287),
+    Vec2::new(-0.608_761_429_008_721, 0.793_353_340_291_235),
+    Vec2::new(-0.793_353_340_291_235, 0.608_761_429_008_721),
+    Vec2::new(-0.923_879_532_511_287, 0.382_683_432_365_09),
+    Vec2::new(-0.991_444_861_373_81, 0.130_526_192_220_052),
+    Vec2::new(-0.991_444_861_373_81, -0.130_526_192_220_051),
+    Vec2::new(-0.923_879_532_511_287, -0.382_683_432_365_09),
+    Vec2::new(-0.793_353_340_291_235, -0.608_761_429_008_721),
+    Vec2::new(-0.608_761_429_008_721, -0.793_353_340_291_235),
+    Vec2::new(-0.382_683_432_365_09, -0.923_879_532_511_287),
+    Vec2::new(-0.130_526_192_220_052, -0.991_444_861_373_81),
+    Vec2::new(0.130_526_192_220_051, -0.991_444_861_373_81),
+    Vec2::new(0.382_683_432_365_09, -0.923_879_532_511_287),
+    Vec2::new(0.608_761_429_008_721, -0.793_353_340_291_235),
+    Vec2::new(0.793_353_340_291_235, -0.608_761_429_008_721),
+    Vec2::new(0.923_879_532_511_287, -0.382_683_432_365_09),
+    Vec2::new(0.991_444_861_373_81, -0.130_526_192_220_052),
+];
+
+const GRAD_TABLE_3D: [Vec3<f64>; 48] = [
+    Vec3::new(1.0, 2.224_744_871_391_589, 2.224_744_871_391_589),
+    Vec3::new(2.224_744_871_391_589, 1.0, 2.224_744_871_391_589),
+    Vec3::new(2.224_744_871_391_589, 2.224_744_871_391_589, 1.0),
+    Vec3::new(0.0, 3.086_266_468_797_202, 1.172_151_342_246_498),
+    Vec3::new(0.0, 1.172_151_342_246_498, 3.086_266_468_797_202),
+    Vec3::new(3.086_266_468_797_202, 0.0, 1.172_151_342_246_498),
+    Vec3::new(1.172_151_342_246_498, 0.0, 3.086_266_468_797_202),
+    Vec3::new(3.086_266_468_797_202, 1.172_151_342_246_498, 0.0),
+    Vec3::new(1.172_151_342_246_498, 3.086_266_468_797_202, 0.0),
+    Vec3::new(-1.0, 2.224_744_871_391_589, 2.224_744_871_391_589),
+    Vec3::new(-2.224_744_871_391_589, 1.0, 2.224_744_871_391_589),
+    Vec3::new(-2.224_744_871_391_589, 2.224_744_871_391_589, 1.0),
+    Vec3::new(-3.086_266_468_797_202, 0.0, 1.172_151_342_246_498),
+    Vec3::new(-1.172_151_342_246_498, 0.0, 3.086_266_468_797_202),
+    Vec3::new(-3.086_266_468_797_202, 1.172_151_342_246_498, 0.0),
+    Vec3::new(-1.172_151_342_246_498, 3.086_266_468_797_202, 0.0),
+    Vec3::new(1.0, -2.224_744_871_391_589, 2.224_744_871_391_589),
+    Vec3::new(2.224_744_871_391_589, -1.0, 2.224_744_871_391_589),
+    Vec3::new(2.224_744_871_391_589, -2.224_744_871_391_589, 1.0),
+    Vec3::new(0.0, -3.086_266_468_797_202, 1.172_151_342_246_498),
+    Vec3::new(0.0, -1.172_151_342_246_498, 3.086_266_468_797_202),
+    Vec3::new(3.086_266_468_797_202, -1.172_151_342_246_498, 0.0),
+    Vec3::new(1.172_151_342_246_498, -3.086_266_468_797_202, 0.0),
+    Vec3::new(-1.0, -2.224_744_871_391_589, 2.224_744_871_391_589),
+    Vec3::new(-2.224_744_871_391_589, -1.0, 2.224_744_871_391_589),
+    Vec3::new(-2.224_744_871_391_589, -2.224_744_871_391_589, 1.0),
+    Vec3::new(-3.086_266_468_797_202, -1.172_151_342_246_498, 0.0),
+    Vec3::new(-1.172_151_342_246_498, -3.086_266_468_797_202, 0.0),
+    Vec3::new(1.0, 2.224_744_871_391_589, -2.224_744_871_391_589),
+    Vec3::new(2.224_744_871_391_589, 1.0, -2.224_744_871_391_589),
+    Vec3::new(2.224_744_871_391_589, 2.224_744_871_391_589, -1.0),
+    Vec3::new(0.0, 3.086_266_468_797_202, -1.172_151_342_246_498),
+    Vec3::new(0.0, 1.172_151_342_246_498, -3.086_266_468_797_202),
+    Vec3::new(3.086_266_468_797_202, 0.0, -1.172_151_342_246_498),
+    Vec3::new(1.172_151_342_246_498, 0.0, -3.086_266_468_797_202),
+    Vec3::new(-1.0, 2.224_744_871_391_589, -2.224_744_871_391_589),
+    Vec3::new(-2.224_744_871_391_589, 1.0, -2.224_744_871_391_589),
+    Vec3::new(-2.224_744_871_391_589, 2.224_744_871_391_589, -1.0),
+    Vec3::new(-3.086_266_468_797_202, 0.0, -1.172_151_342_246_498),
+    Vec3::new(-1.172_151_342_246_498, 0.0, -3.086_266_468_797_202),
+    Vec3::new(1.0, -2.224_744_871_391_589, -2.224_744_871_391_589),
+    Vec3::new(2.224_744_871_391_589, -1.0, -2.224_744_871_391_589),
+    Vec3::new(2.224_744_871_391_589, -2.224_744_871_391_589, -1.0),
+    Vec3::new(0.0, -3.086_266_468_797_202, -1.172_151_342_246_498),
+    Vec3::new(0.0, -1.172_151_342_246_498, -3.086_266_468_797_202),
+    Vec3::new(-1.0, -2.224_744_871_391_589, -2.224_744_871_391_589),
+    Vec3::new(-2.224_744_871_391_589, -1.0, -2.224_744_871_391_589),
+    Vec3::new(-2.224_744_871_391_589, -2.224_744_871_391_589, -1.0),
+];
+
+// Chains the lattice coordinates through the permutation, the last coordinate
+// may be the index of a lattice copy to tell overlapping lattices apart.
+fn hash(perm: &PermTable, coords: &[f64]) -> usize {
+    coords.iter().fold(0, |hash, &coord| {
+        i64::from(perm.perm[((hash ^ coord as i64) & PMASK) as usize])
+    }) as usize
+}
+
+// Contribution of a single lattice point with the gradient `grad`, placed at `delta` from the input.
+fn contribute<Vec: VecType<f64>>(delta: Vec, grad: Vec, radius_squared: f64) -> f64 {
+    let attn = radius_squared - delta.get_attenuation_factor();
+    if attn > 0.0 {
+        return math::powi(attn, 4) * grad.dot(&delta);
+    }
+
+    0.0
+}
+
+fn grad_2d(perm: &PermTable, grid: Vec2<f64>) -> Vec2<f64> {
+    GRAD_TABLE_2D[hash(perm, &[grid.x, grid.y]) % GRAD_TABLE_2D.len()]
+}
+
+fn grad_3d(perm: &PermTable, grid: Vec3<f64>, copy: f64) -> Vec3<f64> {
+    GRAD_TABLE_3D[hash(perm, &[grid.x, grid.y, grid.z, copy]) % GRAD_TABLE_3D.len()]
+}
+
+fn grad_4d(perm: &PermTable, grid: Vec4<f64>, copy: f64) -> Vec4<f64> {
+    GRAD_TABLE_4D[hash(perm, &[grid.x, grid.y, grid.z, grid.w, copy]) % GRAD_TABLE_4D.len()]
+}
+
+// The 3D evaluators work on a rotated input, with the main diagonal of the lattice pointing
+// along an axis it hides the lattice structure from axis aligned slices.
+fn rotate_3d(input: Vec3<f64>) -> Vec3<f64> {
+    let rotation = input.sum() * ROTATE_3D;
+    Vec3::new(rotation, rotation, rotation) - input
+}
+
+// Rotation which keeps the main diagonal along Z, so XY slices look their best.
+fn rotate_3d_improve_xy(input: Vec3<f64>) -> Vec3<f64> {
+    let xy = input.x + input.y;
+    let s2 = xy * ORTHOGONALIZER_3D;
+    let zz = input.z * ROOT3_OVER_3;
+    Vec3::new(
+        input.x + s2 + zz,
+        input.y + s2 + zz,
+        xy * -ROOT3_OVER_3 + zz,
+    )
+}
+
+// Rotation which keeps the main diagonal along Y, so XZ slices look their best.
+fn rotate_3d_improve_xz(input: Vec3<f64>) -> Vec3<f64> {
+    let xz = input.x + input.z;
+    let s2 = xz * ORTHOGONALIZER_3D;
+    let yy = input.y * ROOT3_OVER_3;
+    Vec3::new(
+        input.x + s2 + yy,
+        xz * -ROOT3_OVER_3 + yy,
+        input.z + s2 + yy,
+    )
+}
@@ -0,0 +1,262 @@
+use super::super::utils;
+use super::super::vector::{vec2::Vec2, vec3::Vec3, vec4::Vec4, VecMethods};
+use super::super::{PermTable, SeedAlgorithm, DEFAULT_SEED};
+
+const SKEW_2D: f64 = 0.366_025_403_784_439; // (sqrt(2 + 1) - 1) / 2
+const UNSKEW_2D: f64 = -0.211_324_865_405_187; // (1 / sqrt(2 + 1) - 1) / 2
+const SKEW_4D: f64 = 0.309_016_994_374_947; // (sqrt(4 + 1) - 1) / 4
+const UNSKEW_4D: f64 = -0.138_196_601_125_011; // (1 / sqrt(4 + 1) - 1) / 4
+
+// Kernels reach exactly the nearest neighbours of every lattice point.
+const RADIUS_SQUARED_2D: f64 = 2.0 / 3.0;
+const RADIUS_SQUARED_3D: f64 = 0.75;
+const RADIUS_SQUARED_4D: f64 = 0.8;
+
+// Largest values the sums can reach, so the output fits in [-1, 1]. Found by maximising over
+// the input position, with every lattice point in range taking its most aligned gradient. The
+// 4D one is reached halfway between two neighbouring lattice points: 2 * 0.6^4 * 1.5.
+const NORMALIZING_SCALAR_2D: f64 = 0.054_818_664_956_251_18;
+const NORMALIZING_SCALAR_3D: f64 = 0.278_192_611_752_886_9;
+const NORMALIZING_SCALAR_4D: f64 = 0.388_8;
+
+// Lattice points which can be in range of any input in each sixteenth of the cell, indexed by
+// which of the coordinates inside the cell are past the half.
+#[rustfmt::skip]
+const LOOKUP_4D: [&[[i8; 4]]; 16] = [
+    &[
+        [0, 0, -1, -1], [0, -1, 0, -1], [-1, 0, 0, -1], [0, 0, 0, -1], [0, -1, -1, 0],
+        [-1, 0, -1, 0], [0, 0, -1, 0], [-1, -1, 0, 0], [0, -1, 0, 0], [-1, 0, 0, 0], [0, 0, 0, 0],
+        [1, 0, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0], [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0],
+        [1, 1, 1, 0], [0, 0, 0, 1], [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1], [0, 0, 1, 1],
+        [1, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1],
+    ],
+    &[
+        [0, -1, -1, -1], [0, 0, -1, -1], [0, -1, 0, -1], [0, 0, 0, -1], [1, 0, 0, -1],
+        [0, -1, -1, 0], [0, 0, -1, 0], [1, 0, -1, 0], [0, -1, 0, 0], [1, -1, 0, 0], [0, 0, 0, 0],
+        [1, 0, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0], [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0],
+        [1, 1, 1, 0], [2, 1, 1, 0], [0, 0, 0, 1], [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1],
+        [2, 1, 0, 1], [0, 0, 1, 1], [1, 0, 1, 1], [2, 0, 1, 1], [1, 1, 1, 1], [2, 1, 1, 1],
+    ],
+    &[
+        [-1, 0, -1, -1], [0, 0, -1, -1], [-1, 0, 0, -1], [0, 0, 0, -1], [0, 1, 0, -1],
+        [-1, 0, -1, 0], [0, 0, -1, 0], [0, 1, -1, 0], [-1, 0, 0, 0], [0, 0, 0, 0], [1, 0, 0, 0],
+        [-1, 1, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0], [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0],
+        [1, 1, 1, 0], [1, 2, 1, 0], [0, 0, 0, 1], [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1],
+        [1, 2, 0, 1], [0, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1], [0, 2, 1, 1], [1, 2, 1, 1],
+    ],
+    &[
+        [0, 0, -1, -1], [0, 0, 0, -1], [1, 0, 0, -1], [0, 1, 0, -1], [1, 1, 0, -1], [0, 0, -1, 0],
+        [1, 0, -1, 0], [0, 1, -1, 0], [1, 1, -1, 0], [0, 0, 0, 0], [1, 0, 0, 0], [0, 1, 0, 0],
+        [1, 1, 0, 0], [2, 1, 0, 0], [1, 2, 0, 0], [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0],
+        [1, 1, 1, 0], [2, 1, 1, 0], [1, 2, 1, 0], [0, 0, 0, 1], [1, 0, 0, 1], [0, 1, 0, 1],
+        [1, 1, 0, 1], [2, 1, 0, 1], [1, 2, 0, 1], [1, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1],
+        [2, 1, 1, 1], [1, 2, 1, 1], [2, 2, 1, 1],
+    ],
+    &[
+        [-1, -1, 0, -1], [0, -1, 0, -1], [-1, 0, 0, -1], [0, 0, 0, -1], [0, 0, 1, -1],
+        [-1, -1, 0, 0], [0, -1, 0, 0], [-1, 0, 0, 0], [0, 0, 0, 0], [1, 0, 0, 0], [0, 1, 0, 0],
+        [1, 1, 0, 0], [0, -1, 1, 0], [-1, 0, 1, 0], [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0],
+        [1, 1, 1, 0], [1, 1, 2, 0], [0, 0, 0, 1], [1, 0, 0, 1], [0, 1, 0, 1], [0, 0, 1, 1],
+        [1, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1], [1, 0, 2, 1], [0, 1, 2, 1], [1, 1, 2, 1],
+    ],
+    &[
+        [0, -1, 0, -1], [0, 0, 0, -1], [1, 0, 0, -1], [0, 0, 1, -1], [1, 0, 1, -1], [0, -1, 0, 0],
+        [1, -1, 0, 0], [0, 0, 0, 0], [1, 0, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0], [0, -1, 1, 0],
+        [1, -1, 1, 0], [0, 0, 1, 0], [1, 0, 1, 0], [2, 0, 1, 0], [0, 1, 1, 0], [1, 1, 1, 0],
+        [2, 1, 1, 0], [1, 0, 2, 0], [1, 1, 2, 0], [0, 0, 0, 1], [1, 0, 0, 1], [1, 1, 0, 1],
+        [0, 0, 1, 1], [1, 0, 1, 1], [2, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1], [2, 1, 1, 1],
+        [1, 0, 2, 1], [1, 1, 2, 1], [2, 1, 2, 1],
+    ],
+    &[
+        [-1, 0, 0, -1], [0, 0, 0, -1], [0, 1, 0, -1], [0, 0, 1, -1], [0, 1, 1, -1], [-1, 0, 0, 0],
+        [0, 0, 0, 0], [1, 0, 0, 0], [-1, 1, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0], [-1, 0, 1, 0],
+        [0, 0, 1, 0], [1, 0, 1, 0], [-1, 1, 1, 0], [0, 1, 1, 0], [1, 1, 1, 0], [0, 2, 1, 0],
+        [1, 2, 1, 0], [0, 1, 2, 0], [1, 1, 2, 0], [0, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1],
+        [0, 0, 1, 1], [1, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1], [0, 2, 1, 1], [1, 2, 1, 1],
+        [0, 1, 2, 1], [1, 1, 2, 1], [1, 2, 2, 1],
+    ],
+    &[
+        [0, 0, 0, -1], [1, 0, 0, -1], [0, 1, 0, -1], [0, 0, 1, -1], [0, 0, 0, 0], [1, 0, 0, 0],
+        [0, 1, 0, 0], [1, 1, 0, 0], [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0], [1, 1, 1, 0],
+        [2, 1, 1, 0], [1, 2, 1, 0], [1, 1, 2, 0], [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1],
+        [0, 0, 1, 1], [1, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1], [2, 1, 1, 1], [1, 2, 1, 1],
+        [2, 2, 1, 1], [1, 1, 2, 1], [2, 1, 2, 1], [1, 2, 2, 1], [2, 2, 2, 1],
+    ],
+    &[
+        [-1, -1, -1, 0], [0, -1, -1, 0], [-1, 0, -1, 0], [0, 0, -1, 0], [-1, -1, 0, 0],
+        [0, -1, 0, 0], [-1, 0, 0, 0], [0, 0, 0, 0], [1, 0, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0],
+        [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0], [0, 0, -1, 1], [0, -1, 0, 1], [-1, 0, 0, 1],
+        [0, 0, 0, 1], [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1], [0, 0, 1, 1], [1, 0, 1, 1],
+        [0, 1, 1, 1], [1, 1, 1, 1], [1, 1, 0, 2], [1, 0, 1, 2], [0, 1, 1, 2], [1, 1, 1, 2],
+    ],
+    &[
+        [0, -1, -1, 0], [0, 0, -1, 0], [1, 0, -1, 0], [0, -1, 0, 0], [1, -1, 0, 0], [0, 0, 0, 0],
+        [1, 0, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0], [0, 0, 1, 0], [1, 0, 1, 0], [1, 1, 1, 0],
+        [0, 0, -1, 1], [1, 0, -1, 1], [0, -1, 0, 1], [1, -1, 0, 1], [0, 0, 0, 1], [1, 0, 0, 1],
+        [2, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1], [2, 1, 0, 1], [0, 0, 1, 1], [1, 0, 1, 1],
+        [2, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1], [2, 1, 1, 1], [1, 0, 0, 2], [1, 1, 0, 2],
+        [1, 0, 1, 2], [1, 1, 1, 2], [2, 1, 1, 2],
+    ],
+    &[
+        [-1, 0, -1, 0], [0, 0, -1, 0], [0, 1, -1, 0], [-1, 0, 0, 0], [0, 0, 0, 0], [1, 0, 0, 0],
+        [-1, 1, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0], [0, 0, 1, 0], [0, 1, 1, 0], [1, 1, 1, 0],
+        [0, 0, -1, 1], [0, 1, -1, 1], [-1, 0, 0, 1], [0, 0, 0, 1], [1, 0, 0, 1], [-1, 1, 0, 1],
+        [0, 1, 0, 1], [1, 1, 0, 1], [0, 2, 0, 1], [1, 2, 0, 1], [0, 0, 1, 1], [1, 0, 1, 1],
+        [0, 1, 1, 1], [1, 1, 1, 1], [0, 2, 1, 1], [1, 2, 1, 1], [0, 1, 0, 2], [1, 1, 0, 2],
+        [0, 1, 1, 2], [1, 1, 1, 2], [1, 2, 1, 2],
+    ],
+    &[
+        [0, 0, -1, 0], [1, 0, -1, 0], [0, 1, -1, 0], [0, 0, 0, 0], [1, 0, 0, 0], [0, 1, 0, 0],
+        [1, 1, 0, 0], [1, 0, 1, 0], [0, 1, 1, 0], [1, 1, 1, 0], [0, 0, -1, 1], [0, 0, 0, 1],
+        [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1], [2, 1, 0, 1], [1, 2, 0, 1], [0, 0, 1, 1],
+        [1, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1], [2, 1, 1, 1], [1, 2, 1, 1], [2, 2, 1, 1],
+        [1, 1, 0, 2], [1, 1, 1, 2], [2, 1, 1, 2], [1, 2, 1, 2], [2, 2, 1, 2],
+    ],
+    &[
+        [-1, -1, 0, 0], [0, -1, 0, 0], [-1, 0, 0, 0], [0, 0, 0, 0], [1, 0, 0, 0], [0, 1, 0, 0],
+        [0, -1, 1, 0], [-1, 0, 1, 0], [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0], [1, 1, 1, 0],
+        [0, -1, 0, 1], [-1, 0, 0, 1], [0, 0, 0, 1], [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1],
+        [0, -1, 1, 1], [-1, 0, 1, 1], [0, 0, 1, 1], [1, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1],
+        [0, 0, 2, 1], [1, 0, 2, 1], [0, 1, 2, 1], [1, 1, 2, 1], [0, 0, 1, 2], [1, 0, 1, 2],
+        [0, 1, 1, 2], [1, 1, 1, 2], [1, 1, 2, 2],
+    ],
+    &[
+        [0, -1, 0, 0], [1, -1, 0, 0], [0, 0, 0, 0], [1, 0, 0, 0], [1, 1, 0, 0], [0, -1, 1, 0],
+        [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0], [1, 1, 1, 0], [0, -1, 0, 1], [0, 0, 0, 1],
+        [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1], [0, 0, 1, 1], [1, 0, 1, 1], [2, 0, 1, 1],
+        [0, 1, 1, 1], [1, 1, 1, 1], [2, 1, 1, 1], [1, 0, 2, 1], [1, 1, 2, 1], [2, 1, 2, 1],
+        [1, 0, 1, 2], [1, 1, 1, 2], [2, 1, 1, 2], [1, 1, 2, 2], [2, 1, 2, 2],
+    ],
+    &[
+        [-1, 0, 0, 0], [0, 0, 0, 0], [-1, 1, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0], [-1, 0, 1, 0],
+        [0, 0, 1, 0], [1, 0, 1, 0], [0, 1, 1, 0], [1, 1, 1, 0], [-1, 0, 0, 1], [0, 0, 0, 1],
+        [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1], [0, 0, 1, 1], [1, 0, 1, 1], [0, 1, 1, 1],
+        [1, 1, 1, 1], [0, 2, 1, 1], [1, 2, 1, 1], [0, 1, 2, 1], [1, 1, 2, 1], [1, 2, 2, 1],
+        [0, 1, 1, 2], [1, 1, 1, 2], [1, 2, 1, 2], [1, 1, 2, 2], [1, 2, 2, 2],
+    ],
+    &[
+        [0, 0, 0, 0], [1, 0, 0, 0], [0, 1, 0, 0], [1, 1, 0, 0], [0, 0, 1, 0], [1, 0, 1, 0],
+        [0, 1, 1, 0], [1, 1, 1, 0], [0, 0, 0, 1], [1, 0, 0, 1], [0, 1, 0, 1], [1, 1, 0, 1],
+        [0, 0, 1, 1], [1, 0, 1, 1], [0, 1, 1, 1], [1, 1, 1, 1], [2, 1, 1, 1], [1, 2, 1, 1],
+        [2, 2, 1, 1], [1, 1, 2, 1], [2, 1, 2, 1], [1, 2, 2, 1], [1, 1, 1, 2], [2, 1, 1, 2],
+        [1, 2, 1, 2], [1, 1, 2, 2],
+    ],
+];
+
+// OpenSimplex2S, the smooth variant of OpenSimplex2. Kernels are larger than in OpenSimplex2F
+// and every lattice point in range contributes, which costs more but looks smoother.
+pub struct OpenSimplex2S {
+    perm: PermTable,
+}
+
+impl OpenSimplex2S {
+    pub fn new(custom_seed: Option<i64>) -> Self {
+        let seed = match custom_seed {
+            Some(value) => value,
+            None => DEFAULT_SEED,
+        };
+
+        Self {
+            perm: SeedAlgorithm::AdvancingLcg.generate_perm_array(seed),
+        }
+    }
+
+    pub fn eval_2d(&self, x: f64, y: f64) -> f64 {
+        let input = Vec2::new(x, y);
+        let skewed = input + Vec2::new(SKEW_2D, SKEW_2D) * input.sum();
+        let grid = skewed.map(utils::floor).map(utils::to_f64);
+        let ins = skewed - grid;
+
+        let contribute = |x: f64, y: f64| {
+            let delta = ins - Vec2::new(x, y);
+            let delta = delta + Vec2::new(UNSKEW_2D, UNSKEW_2D) * delta.sum();
+            super::contribute(
+                delta,
+                super::grad_2d(&self.perm, grid + Vec2::new(x, y)),
+                RADIUS_SQUARED_2D,
+            )
+        };
+
+        // Besides the corners of the cell, one more point along each axis can be in range,
+        // depending on which half of the cell the input is in.
+        let extra_x = if ins.x < 0.5 {
+            contribute(-1.0, 0.0)
+        } else {
+            contribute(2.0, 1.0)
+        };
+        let extra_y = if ins.y < 0.5 {
+            contribute(0.0, -1.0)
+        } else {
+            contribute(1.0, 2.0)
+        };
+
+        let value = contribute(0.0, 0.0)
+            + contribute(1.0, 0.0)
+            + contribute(0.0, 1.0)
+            + contribute(1.0, 1.0)
+            + extra_x
+            + extra_y;
+
+        value / NORMALIZING_SCALAR_2D
+    }
+
+    pub fn eval_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        self.eval_3d_rotated(super::rotate_3d(Vec3::new(x, y, z)))
+    }
+
+    // Better suited for terrain or 2D slices where Z is the vertical axis or the time.
+    pub fn eval_3d_improve_xy(&self, x: f64, y: f64, z: f64) -> f64 {
+        self.eval_3d_rotated(super::rotate_3d_improve_xy(Vec3::new(x, y, z)))
+    }
+
+    // Better suited for terrain where Y is the vertical axis.
+    pub fn eval_3d_improve_xz(&self, x: f64, y: f64, z: f64) -> f64 {
+        self.eval_3d_rotated(super::rotate_3d_improve_xz(Vec3::new(x, y, z)))
+    }
+
+    pub fn eval_4d(&self, x: f64, y: f64, z: f64, w: f64) -> f64 {
+        let input = Vec4::new(x, y, z, w);
+        let skewed = input + Vec4::new(SKEW_4D, SKEW_4D, SKEW_4D, SKEW_4D) * input.sum();
+        let grid = skewed.map(utils::floor).map(utils::to_f64);
+        let ins = skewed - grid;
+
+        let index = (ins.x >= 0.5) as usize
+            | ((ins.y >= 0.5) as usize) << 1
+            | ((ins.z >= 0.5) as usize) << 2
+            | ((ins.w >= 0.5) as usize) << 3;
+
+        let value: f64 = LOOKUP_4D[index]
+            .iter()
+            .map(|point| {
+                let point = Vec4::new(point[0], point[1], point[2], point[3]).map(f64::from);
+                let delta = ins - point;
+                let delta =
+                    delta + Vec4::new(UNSKEW_4D, UNSKEW_4D, UNSKEW_4D, UNSKEW_4D) * delta.sum();
+                let grad = super::grad_4d(&self.perm, grid + point, 0.0);
+                super::contribute(delta, grad, RADIUS_SQUARED_4D)
+            })
+            .sum();
+
+        value / NORMALIZING_SCALAR_4D
+    }
+
+    // Two interleaved cubic lattices form a body centered cubic lattice, every corner
+    // of the cells around the input in both of them can be in range.
+    fn eval_3d_rotated(&self, rotated: Vec3<f64>) -> f64 {
+        let mut value = 0.0;
+        for copy in 0..2 {
+            // The second lattice is offset by half a cell on every axis
+            let shifted = rotated + Vec3::new(0.5, 0.5, 0.5) * copy as f64;
+            let grid = shifted.map(utils::floor).map(utils::to_f64);
+            let ins = shifted - grid;
+
+            for corner in 0..8 {
+                let point = Vec3::new(corner & 1, (corner >> 1) & 1, corner >> 2).map(f64::from);
+                let grad = super::grad_3d(&self.perm, grid + point, copy as f64);
+                value += super::contribute(ins - point, grad, RADIUS_SQUARED_3D);
+            }
+        }
+
+        value / NORMALIZING_SCALAR_3D
+    }
+}
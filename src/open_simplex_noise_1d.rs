@@ -0,0 +1,104 @@
+use super::float::Float;
+use super::hash::{GradientIndex, LatticeHash};
+use super::utils::{self, Contribution};
+use super::vector::{vec1::Vec1, VecMethods};
+use super::{NoiseEvaluator, NoiseVersion};
+
+// Largest possible sum, reached halfway between two lattice points with opposite gradients
+// of the largest magnitude: 2 * 4 * 0.5 * (2 - 0.5^2)^4
+pub(crate) const NORMALIZING_SCALAR: f64 = 37.515_625;
+
+const GRAD_TABLE: [Vec1<f64>; 8] = [
+    Vec1::new(1.0),
+    Vec1::new(2.0),
+    Vec1::new(3.0),
+    Vec1::new(4.0),
+    Vec1::new(-1.0),
+    Vec1::new(-2.0),
+    Vec1::new(-3.0),
+    Vec1::new(-4.0),
+];
+
+// The 1D simplex lattice is the integers, so there is nothing to stretch or squish. Every point
+// gets the contributions of the lattice points within the same radius as in the higher
+// dimensions, instead of being a slice of 2D noise which leaves its values unevenly spread.
+pub struct OpenSimplexNoise1D {}
+
+impl<F: Float> NoiseEvaluator<Vec1<F>, F> for OpenSimplexNoise1D {
+    fn stretch_point() -> Vec1<F> {
+        Vec1::new(F::from_f64(0.0))
+    }
+
+    fn squish_point() -> Vec1<F> {
+        Vec1::new(F::from_f64(0.0))
+    }
+
+    fn extrapolate<H: LatticeHash<Vec1<F>>>(grid: Vec1<F>, delta: Vec1<F>, perm: &H) -> F {
+        Self::get_grad(grid, perm).dot(&delta)
+    }
+
+    fn get_grad<H: LatticeHash<Vec1<F>>>(grid: Vec1<F>, perm: &H) -> Vec1<F> {
+        GRAD_TABLE[perm.gradient_index(grid)].map(F::from_f64)
+    }
+
+    fn eval<H: LatticeHash<Vec1<F>>>(input: Vec1<F>, perm: &H, version: NoiseVersion) -> F {
+        Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute::<Self, F, Vec1<F>, H>,
+        )
+    }
+
+    fn eval_with_gradient<H: LatticeHash<Vec1<F>>>(
+        input: Vec1<F>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> (F, Vec1<F>) {
+        let result = Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute_with_gradient::<Self, F, Vec1<F>, H>,
+        );
+        (result.value, result.gradient)
+    }
+}
+
+impl OpenSimplexNoise1D {
+    // Walks the lattice points around `input`, summing what `contribution` returns for each of
+    // them. There is only one version of the 1D noise, `version` is taken for symmetry with the
+    // other dimensions.
+    pub fn evaluate<F: Float, R: Contribution<F>, H: LatticeHash<Vec1<F>>>(
+        input: Vec1<F>,
+        perm: &H,
+        _version: NoiseVersion,
+        contribution: impl Fn(Vec1<F>, Vec1<F>, Vec1<F>, &H) -> R,
+    ) -> R {
+        let grid = input.map(utils::floor).map(F::from_i64);
+        let origin = input - grid;
+
+        let contribute = |x: f64| contribution(Vec1::new(F::from_f64(x)), origin, grid, perm);
+
+        // Lattice points closer than sqrt(2): both ends of the cell and the next one past the
+        // nearer end
+        let outer = if origin.x < F::from_f64(0.5) {
+            contribute(-1.0)
+        } else {
+            contribute(2.0)
+        };
+        let value = contribute(0.0) + contribute(1.0) + outer;
+
+        value / F::from_f64(NORMALIZING_SCALAR)
+    }
+}
+
+impl<F> GradientIndex for Vec1<F> {
+    fn from_hash(hash: i64) -> usize {
+        grad_index_1d(hash)
+    }
+}
+
+const fn grad_index_1d(hash: i64) -> usize {
+    (hash & 0x07) as usize
+}
@@ -1,12 +1,13 @@
-use super::utils;
+use super::float::Float;
+use super::hash::{GradientIndex, LatticeHash};
+use super::utils::{self, Contribution};
 use super::vector::{vec2::Vec2, VecMethods};
-use super::NoiseEvaluator;
-use super::PermTable;
+use super::{NoiseEvaluator, NoiseVersion};
 
 const STRETCH: f64 = -0.211_324_865_405_187; // (1 / sqrt(2 + 1) - 1) / 2
 const SQUISH: f64 = 0.366_025_403_784_439; // (sqrt(2 + 1) - 1) / 2
 
-const NORMALIZING_SCALAR: f64 = 47.0;
+pub(crate) const NORMALIZING_SCALAR: f64 = 47.0;
 
 const GRAD_TABLE: [Vec2<f64>; 8] = [
     Vec2::new(5.0, 2.0),
@@ -21,57 +22,161 @@ const GRAD_TABLE: [Vec2<f64>; 8] = [
 
 pub struct OpenSimplexNoise2D {}
 
-impl NoiseEvaluator<Vec2<f64>> for OpenSimplexNoise2D {
-    const STRETCH_POINT: Vec2<f64> = Vec2::new(STRETCH, STRETCH);
-    const SQUISH_POINT: Vec2<f64> = Vec2::new(SQUISH, SQUISH);
+impl<F: Float> NoiseEvaluator<Vec2<F>, F> for OpenSimplexNoise2D {
+    fn stretch_point() -> Vec2<F> {
+        Self::STRETCH_POINT.map(F::from_f64)
+    }
 
-    fn extrapolate(grid: Vec2<f64>, delta: Vec2<f64>, perm: &PermTable) -> f64 {
-        let point = GRAD_TABLE[Self::get_grad_table_index(grid, perm)];
-        point.x * delta.x + point.y * delta.y
+    fn squish_point() -> Vec2<F> {
+        Self::SQUISH_POINT.map(F::from_f64)
     }
 
-    fn eval(input: Vec2<f64>, perm: &PermTable) -> f64 {
-        let stretch: Vec2<f64> = input + (Self::STRETCH_POINT * input.sum());
-        let grid = stretch.map(utils::floor).map(utils::to_f64);
+    fn extrapolate<H: LatticeHash<Vec2<F>>>(grid: Vec2<F>, delta: Vec2<F>, perm: &H) -> F {
+        Self::get_grad(grid, perm).dot(&delta)
+    }
 
-        let squashed: Vec2<f64> = grid + (Self::SQUISH_POINT * grid.sum());
-        let ins = stretch - grid;
-        let origin = input - squashed;
+    fn get_grad<H: LatticeHash<Vec2<F>>>(grid: Vec2<F>, perm: &H) -> Vec2<F> {
+        GRAD_TABLE[perm.gradient_index(grid)].map(F::from_f64)
+    }
+
+    fn eval<H: LatticeHash<Vec2<F>>>(input: Vec2<F>, perm: &H, version: NoiseVersion) -> F {
+        Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute::<Self, F, Vec2<F>, H>,
+        )
+    }
 
-        OpenSimplexNoise2D::get_value(grid, origin, ins, perm)
+    fn eval_with_gradient<H: LatticeHash<Vec2<F>>>(
+        input: Vec2<F>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> (F, Vec2<F>) {
+        let result = Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute_with_gradient::<Self, F, Vec2<F>, H>,
+        );
+        (result.value, result.gradient)
     }
 }
 
 impl OpenSimplexNoise2D {
-    fn get_value(grid: Vec2<f64>, origin: Vec2<f64>, ins: Vec2<f64>, perm: &PermTable) -> f64 {
-        let contribute = |x, y| -> f64 {
-            utils::contribute::<OpenSimplexNoise2D, Vec2<f64>>(Vec2::new(x, y), origin, grid, perm)
-        };
+    pub(crate) const STRETCH_POINT: Vec2<f64> = Vec2::new(STRETCH, STRETCH);
+    pub(crate) const SQUISH_POINT: Vec2<f64> = Vec2::new(SQUISH, SQUISH);
+
+    // Walks the lattice points around `input`, summing what `contribution` returns for each of
+    // them. Passing `contribute::<E, ...>` evaluates the noise with the gradients of `E`.
+    pub fn evaluate<F: Float, R: Contribution<F>, H: LatticeHash<Vec2<F>>>(
+        input: Vec2<F>,
+        perm: &H,
+        version: NoiseVersion,
+        contribution: impl Fn(Vec2<F>, Vec2<F>, Vec2<F>, &H) -> R,
+    ) -> R {
+        let stretch: Vec2<F> = input + (Self::stretch_point() * input.sum());
+        let grid = stretch.map(utils::floor).map(F::from_i64);
+
+        let squashed: Vec2<F> = grid + (Self::squish_point() * grid.sum());
+        let ins = stretch - grid;
+        let origin = input - squashed;
+
+        Self::get_value(grid, origin, ins, perm, version, contribution)
+    }
+
+    // Evaluates a point whose lattice cell was already located, `squashed` being the origin
+    // of the cell in input space. Batch evaluation shares it between points of the same cell.
+    pub(crate) fn eval_in_cell<F: Float, H: LatticeHash<Vec2<F>>>(
+        input: Vec2<F>,
+        stretch: Vec2<F>,
+        grid: Vec2<F>,
+        squashed: Vec2<F>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> F {
+        let ins = stretch - grid;
+        let origin = input - squashed;
+
+        Self::get_value(
+            grid,
+            origin,
+            ins,
+            perm,
+            version,
+            utils::contribute::<Self, F, Vec2<F>, H>,
+        )
+    }
+
+    // Evaluates a point given directly in the stretched (lattice) space.
+    pub(crate) fn eval_stretched<F: Float, H: LatticeHash<Vec2<F>>>(
+        stretch: Vec2<F>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> F {
+        let grid = stretch.map(utils::floor).map(F::from_i64);
+        let ins = stretch - grid;
+        let origin = ins + (Self::squish_point() * ins.sum());
+
+        Self::get_value(
+            grid,
+            origin,
+            ins,
+            perm,
+            version,
+            utils::contribute::<Self, F, Vec2<F>, H>,
+        )
+    }
+
+    fn get_value<F: Float, R: Contribution<F>, H: LatticeHash<Vec2<F>>>(
+        grid: Vec2<F>,
+        origin: Vec2<F>,
+        ins: Vec2<F>,
+        perm: &H,
+        version: NoiseVersion,
+        contribution: impl Fn(Vec2<F>, Vec2<F>, Vec2<F>, &H) -> R,
+    ) -> R {
+        let contribute =
+            |x: f64, y: f64| contribution(Vec2::new(x, y).map(F::from_f64), origin, grid, perm);
 
         let value = contribute(1.0, 0.0)
             + contribute(0.0, 1.0)
-            + Self::evaluate_inside_triangle(ins, contribute);
+            + Self::evaluate_inside_triangle(ins, version, contribute);
 
-        value / NORMALIZING_SCALAR
+        value / F::from_f64(NORMALIZING_SCALAR)
     }
 
-    fn evaluate_inside_triangle(ins: Vec2<f64>, contribute: impl Fn(f64, f64) -> f64) -> f64 {
+    fn evaluate_inside_triangle<F: Float, R: Contribution<F>>(
+        ins: Vec2<F>,
+        version: NoiseVersion,
+        contribute: impl Fn(f64, f64) -> R,
+    ) -> R {
         let in_sum = ins.sum();
         let factor_point = match in_sum {
-            x if x <= 1.0 => Vec2::new(0.0, 0.0),
+            x if x <= F::from_f64(1.0) => Vec2::new(0.0, 0.0),
             _ => Vec2::new(1.0, 1.0),
         };
-        Self::evaluate_inside_triangle_at(factor_point, in_sum, ins, contribute)
+        Self::evaluate_inside_triangle_at(factor_point, in_sum, ins, version, contribute)
     }
 
-    fn evaluate_inside_triangle_at(
+    fn evaluate_inside_triangle_at<F: Float, R: Contribution<F>>(
         factor_point: Vec2<f64>,
-        in_sum: f64,
-        ins: Vec2<f64>,
-        contribute: impl Fn(f64, f64) -> f64,
-    ) -> f64 {
-        let zins = 1.0 + factor_point.x - in_sum;
-        let point = if zins > ins.x || zins > ins.y {
+        in_sum: F,
+        ins: Vec2<F>,
+        version: NoiseVersion,
+        contribute: impl Fn(f64, f64) -> R,
+    ) -> R {
+        let zins = F::from_f64(1.0 + factor_point.x) - in_sum;
+        // The vertex at the factor point is approached from the opposite side in the (1, 1) triangle,
+        // which 0.3 releases didn't account for
+        let is_closer = |score: F| {
+            if factor_point.x == 0.0 || version == NoiseVersion::V0_3 {
+                zins > score
+            } else {
+                zins < score
+            }
+        };
+        let point = if is_closer(ins.x) || is_closer(ins.y) {
             // (0, 0) is one of the closest two triangular vertices
             if ins.x > ins.y {
                 Vec2::new(1.0 + factor_point.x, -1.0 + factor_point.y)
@@ -85,9 +190,14 @@ impl OpenSimplexNoise2D {
 
         contribute(0.0 + factor_point.x, 0.0 + factor_point.y) + contribute(point.x, point.y)
     }
+}
 
-    fn get_grad_table_index(grid: Vec2<f64>, perm: &PermTable) -> usize {
-        let index0 = ((perm[(grid.x as i64 & 0xFF) as usize] + grid.y as i64) & 0xFF) as usize;
-        ((perm[index0] & 0x0E) >> 1) as usize
+impl<F> GradientIndex for Vec2<F> {
+    fn from_hash(hash: i64) -> usize {
+        grad_index_2d(hash)
     }
 }
+
+pub(crate) const fn grad_index_2d(hash: i64) -> usize {
+    ((hash & 0x0E) >> 1) as usize
+}
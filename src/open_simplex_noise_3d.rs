@@ -1,12 +1,14 @@
-use super::utils;
+use super::float::Float;
+use super::hash::{GradientIndex, LatticeHash};
+use super::utils::{self, Contribution};
+use super::vector::vec2::Vec2;
 use super::vector::{vec3::Vec3, VecMethods};
-use super::NoiseEvaluator;
-use super::{vector::vec2::Vec2, PermTable};
+use super::{NoiseEvaluator, NoiseVersion};
 
 const STRETCH: f64 = -1.0 / 6.0; // (1 / sqrt(3 + 1) - 1) / 3
 const SQUISH: f64 = 1.0 / 3.0; // (sqrt(3 + 1) - 1) / 3
 
-const NORMALIZING_SCALAR: f64 = 103.0;
+pub(crate) const NORMALIZING_SCALAR: f64 = 103.0;
 
 const GRAD_TABLE: [Vec3<f64>; 24] = [
     Vec3::new(-11.0, 4.0, 4.0),
@@ -35,48 +37,179 @@ const GRAD_TABLE: [Vec3<f64>; 24] = [
     Vec3::new(4.0, -4.0, -11.0),
 ];
 
+// Every lattice point that can be in range of a point of the cell, for `ins` sorted in
+// decreasing order: the vertices of its region first, then the points around them. The
+// regions are the tetrahedron at (0, 0, 0), the octahedron and the tetrahedron at (1, 1, 1).
+pub(crate) const LATTICE_POINTS: [[[f64; 3]; 10]; 3] = [
+    [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+        [1.0, 1.0, 0.0],
+        [1.0, 0.0, 1.0],
+        [1.0, 1.0, -1.0],
+        [1.0, -1.0, 0.0],
+        [1.0, 0.0, -1.0],
+        [0.0, 1.0, -1.0],
+    ],
+    [
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+        [1.0, 1.0, 0.0],
+        [1.0, 0.0, 1.0],
+        [0.0, 1.0, 1.0],
+        [0.0, 0.0, 0.0],
+        [1.0, 1.0, 1.0],
+        [2.0, 0.0, 0.0],
+        [1.0, 1.0, -1.0],
+    ],
+    [
+        [1.0, 1.0, 0.0],
+        [1.0, 0.0, 1.0],
+        [0.0, 1.0, 1.0],
+        [1.0, 1.0, 1.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [2.0, 0.0, 0.0],
+        [2.0, 1.0, 0.0],
+        [2.0, 0.0, 1.0],
+        [1.0, 2.0, 0.0],
+    ],
+];
+
 pub struct OpenSimplexNoise3D {}
 
-impl NoiseEvaluator<Vec3<f64>> for OpenSimplexNoise3D {
-    const STRETCH_POINT: Vec3<f64> = Vec3::new(STRETCH, STRETCH, STRETCH);
-    const SQUISH_POINT: Vec3<f64> = Vec3::new(SQUISH, SQUISH, SQUISH);
+impl<F: Float> NoiseEvaluator<Vec3<F>, F> for OpenSimplexNoise3D {
+    fn stretch_point() -> Vec3<F> {
+        Self::STRETCH_POINT.map(F::from_f64)
+    }
 
-    fn extrapolate(grid: Vec3<f64>, delta: Vec3<f64>, perm: &PermTable) -> f64 {
-        let point = GRAD_TABLE[Self::get_grad_table_index(grid, perm)];
+    fn squish_point() -> Vec3<F> {
+        Self::SQUISH_POINT.map(F::from_f64)
+    }
 
-        point.x * delta.x + point.y * delta.y + point.z * delta.z
+    fn extrapolate<H: LatticeHash<Vec3<F>>>(grid: Vec3<F>, delta: Vec3<F>, perm: &H) -> F {
+        Self::get_grad(grid, perm).dot(&delta)
     }
 
-    fn eval(input: Vec3<f64>, perm: &PermTable) -> f64 {
-        let stretch: Vec3<f64> = input + (Self::STRETCH_POINT * input.sum());
-        let grid = stretch.map(utils::floor).map(utils::to_f64);
+    fn get_grad<H: LatticeHash<Vec3<F>>>(grid: Vec3<F>, perm: &H) -> Vec3<F> {
+        GRAD_TABLE[perm.gradient_index(grid)].map(F::from_f64)
+    }
 
-        let squashed: Vec3<f64> = grid + (Self::SQUISH_POINT * grid.sum());
-        let ins = stretch - grid;
-        let origin = input - squashed;
+    fn eval<H: LatticeHash<Vec3<F>>>(input: Vec3<F>, perm: &H, version: NoiseVersion) -> F {
+        Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute::<Self, F, Vec3<F>, H>,
+        )
+    }
 
-        Self::get_value(grid, origin, ins, perm)
+    fn eval_with_gradient<H: LatticeHash<Vec3<F>>>(
+        input: Vec3<F>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> (F, Vec3<F>) {
+        let result = Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute_with_gradient::<Self, F, Vec3<F>, H>,
+        );
+        (result.value, result.gradient)
     }
 }
 
 impl OpenSimplexNoise3D {
-    fn get_value(grid: Vec3<f64>, origin: Vec3<f64>, ins: Vec3<f64>, perm: &PermTable) -> f64 {
+    pub(crate) const STRETCH_POINT: Vec3<f64> = Vec3::new(STRETCH, STRETCH, STRETCH);
+    pub(crate) const SQUISH_POINT: Vec3<f64> = Vec3::new(SQUISH, SQUISH, SQUISH);
+
+    // Walks the lattice points around `input`, summing what `contribution` returns for each of
+    // them. Passing `contribute::<E, ...>` evaluates the noise with the gradients of `E`.
+    pub fn evaluate<F: Float, R: Contribution<F>, H: LatticeHash<Vec3<F>>>(
+        input: Vec3<F>,
+        perm: &H,
+        version: NoiseVersion,
+        contribution: impl Fn(Vec3<F>, Vec3<F>, Vec3<F>, &H) -> R,
+    ) -> R {
+        let stretch: Vec3<F> = input + (Self::stretch_point() * input.sum());
+        let grid = stretch.map(utils::floor).map(F::from_i64);
+
+        let squashed: Vec3<F> = grid + (Self::squish_point() * grid.sum());
+        let ins = stretch - grid;
+        let origin = input - squashed;
+
+        Self::get_value(grid, origin, ins, perm, version, contribution)
+    }
+
+    // Evaluates a point whose lattice cell was already located, `squashed` being the origin
+    // of the cell in input space. Batch evaluation shares it between points of the same cell.
+    pub(crate) fn eval_in_cell<F: Float, H: LatticeHash<Vec3<F>>>(
+        input: Vec3<F>,
+        stretch: Vec3<F>,
+        grid: Vec3<F>,
+        squashed: Vec3<F>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> F {
+        let ins = stretch - grid;
+        let origin = input - squashed;
+
+        Self::get_value(
+            grid,
+            origin,
+            ins,
+            perm,
+            version,
+            utils::contribute::<Self, F, Vec3<F>, H>,
+        )
+    }
+
+    // Evaluates a point given directly in the stretched (lattice) space.
+    pub(crate) fn eval_stretched<F: Float, H: LatticeHash<Vec3<F>>>(
+        stretch: Vec3<F>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> F {
+        let grid = stretch.map(utils::floor).map(F::from_i64);
+        let ins = stretch - grid;
+        let origin = ins + (Self::squish_point() * ins.sum());
+
+        Self::get_value(
+            grid,
+            origin,
+            ins,
+            perm,
+            version,
+            utils::contribute::<Self, F, Vec3<F>, H>,
+        )
+    }
+
+    fn get_value<F: Float, R: Contribution<F>, H: LatticeHash<Vec3<F>>>(
+        grid: Vec3<F>,
+        origin: Vec3<F>,
+        ins: Vec3<F>,
+        perm: &H,
+        version: NoiseVersion,
+        contribution: impl Fn(Vec3<F>, Vec3<F>, Vec3<F>, &H) -> R,
+    ) -> R {
         let contribute = |x: f64, y: f64, z: f64| {
-            utils::contribute::<OpenSimplexNoise3D, Vec3<f64>>(
-                Vec3::new(x, y, z),
-                origin,
-                grid,
-                perm,
-            )
+            contribution(Vec3::new(x, y, z).map(F::from_f64), origin, grid, perm)
         };
 
+        if version != NoiseVersion::V0_3 {
+            return Self::sum_lattice_points(ins, contribute) / F::from_f64(NORMALIZING_SCALAR);
+        }
+
         // Sum those together to get a value that determines the region.
         let value = match ins.sum() {
-            in_sum if in_sum <= 1.0 => {
+            in_sum if in_sum <= F::from_f64(1.0) => {
                 // Inside the tetrahedron (3-Simplex) at (0, 0, 0)
                 Self::inside_tetrahedron_at_0_0_0(ins, in_sum, contribute)
             }
-            in_sum if in_sum >= 2.0 => {
+            in_sum if in_sum >= F::from_f64(2.0) => {
                 // Inside the tetrahedron (3-Simplex) at (1, 1, 1)
                 Self::inside_tetrahedron_at_1_1_1(ins, in_sum, contribute)
             }
@@ -86,14 +219,45 @@ impl OpenSimplexNoise3D {
             }
         };
 
-        value / NORMALIZING_SCALAR
+        value / F::from_f64(NORMALIZING_SCALAR)
+    }
+
+    // Adds up every lattice point of `lattice_points`. Points out of range contribute nothing,
+    // so none can drop out while it still has some weight.
+    fn sum_lattice_points<F: Float, R: Contribution<F>>(
+        ins: Vec3<F>,
+        contribute: impl Fn(f64, f64, f64) -> R,
+    ) -> R {
+        Self::lattice_points(ins).fold(R::default(), |value, point| {
+            value + contribute(point.x, point.y, point.z)
+        })
     }
 
-    fn inside_tetrahedron_at_0_0_0(
-        ins: Vec3<f64>,
-        in_sum: f64,
-        contribute: impl Fn(f64, f64, f64) -> f64,
-    ) -> f64 {
+    // The lattice points of `LATTICE_POINTS` for the region of `ins`, with the axes of the
+    // table reordered the way `ins` is ordered. Batch evaluation picks them lane by lane.
+    pub(crate) fn lattice_points<F: Float>(ins: Vec3<F>) -> impl Iterator<Item = Vec3<f64>> {
+        // Position of each coordinate in decreasing order, ties going to the first axis
+        let rank = Vec3::new(
+            usize::from(ins.y > ins.x) + usize::from(ins.z > ins.x),
+            usize::from(ins.x >= ins.y) + usize::from(ins.z > ins.y),
+            usize::from(ins.x >= ins.z) + usize::from(ins.y >= ins.z),
+        );
+        let region = match ins.sum() {
+            in_sum if in_sum <= F::from_f64(1.0) => 0,
+            in_sum if in_sum >= F::from_f64(2.0) => 2,
+            _ => 1,
+        };
+
+        LATTICE_POINTS[region]
+            .iter()
+            .map(move |point| Vec3::new(point[rank.x], point[rank.y], point[rank.z]))
+    }
+
+    fn inside_tetrahedron_at_0_0_0<F: Float, R: Contribution<F>>(
+        ins: Vec3<F>,
+        in_sum: F,
+        contribute: impl Fn(f64, f64, f64) -> R,
+    ) -> R {
         // Determine which two of (0, 0, 1), (0, 1, 0), (1, 0, 0) are closest.
         let (score, point) = Self::determine_closest_point(
             Vec2::new(ins.x, ins.y),
@@ -104,12 +268,8 @@ impl OpenSimplexNoise3D {
 
         // Now we determine the two lattice points not part of the tetrahedron that may contribute.
         // This depends on the closest two tetrahedral vertices, including (0, 0, 0)
-        let value = Self::determine_lattice_points_including_0_0_0(
-            in_sum,
-            score,
-            point,
-            |x, y, z| contribute(x, y, z),
-        );
+        let value =
+            Self::determine_lattice_points_including_0_0_0(in_sum, score, point, &contribute);
 
         value
             + contribute(0.0, 0.0, 0.0)
@@ -118,11 +278,11 @@ impl OpenSimplexNoise3D {
             + contribute(0.0, 0.0, 1.0)
     }
 
-    fn inside_tetrahedron_at_1_1_1(
-        ins: Vec3<f64>,
-        in_sum: f64,
-        contribute: impl Fn(f64, f64, f64) -> f64,
-    ) -> f64 {
+    fn inside_tetrahedron_at_1_1_1<F: Float, R: Contribution<F>>(
+        ins: Vec3<F>,
+        in_sum: F,
+        contribute: impl Fn(f64, f64, f64) -> R,
+    ) -> R {
         // Determine which two tetrahedral vertices are the closest, out of (1, 1, 0), (1, 0, 1), (0, 1, 1) but not (1, 1, 1).
         let (score, point) = Self::determine_closest_point(
             Vec2::new(ins.x, ins.y),
@@ -133,12 +293,8 @@ impl OpenSimplexNoise3D {
 
         // Now we determine the two lattice points not part of the tetrahedron that may contribute.
         // This depends on the closest two tetrahedral vertices, including (1, 1, 1)
-        let value = Self::determine_lattice_points_including_1_1_1(
-            in_sum,
-            score,
-            point,
-            |x, y, z| contribute(x, y, z),
-        );
+        let value =
+            Self::determine_lattice_points_including_1_1_1(in_sum, score, point, &contribute);
 
         value
             + contribute(1.0, 1.0, 0.0)
@@ -147,10 +303,10 @@ impl OpenSimplexNoise3D {
             + contribute(1.0, 1.0, 1.0)
     }
 
-    fn inside_octahedron_in_between(
-        ins: Vec3<f64>,
-        contribute: impl Fn(f64, f64, f64) -> f64,
-    ) -> f64 {
+    fn inside_octahedron_in_between<F: Float, R: Contribution<F>>(
+        ins: Vec3<F>,
+        contribute: impl Fn(f64, f64, f64) -> R,
+    ) -> R {
         let (is_further_side, point) = Self::determine_further_side(ins);
 
         // Where each of the two closest points are determines how the extra two vertices are calculated.
@@ -173,6 +329,7 @@ impl OpenSimplexNoise3D {
                 // Other extra point is based on the omitted axis.
                 let closest = point.x | point.y;
 
+                // 0.3 releases match 4 here, so (1, -1, 1) is swapped for (-1, 1, 1)
                 contribute(0.0, 0.0, 0.0)
                     + match closest {
                         3 => contribute(1.0, 1.0, -1.0),
@@ -210,12 +367,13 @@ impl OpenSimplexNoise3D {
             + contribute(0.0, 1.0, 1.0)
     }
 
-    fn decide_between_points(ins: Vec3<f64>) -> (Vec2<f64>, Vec2<i32>, Vec2<bool>) {
-        let decide_between_points = |p: f64, point_val: (i32, i32)| {
-            if p > 1.0 {
-                return (p - 1.0, point_val.0, true);
+    fn decide_between_points<F: Float>(ins: Vec3<F>) -> (Vec2<F>, Vec2<i32>, Vec2<bool>) {
+        let decide_between_points = |p: F, point_val: (i32, i32)| {
+            let one = F::from_f64(1.0);
+            if p > one {
+                return (p - one, point_val.0, true);
             }
-            (1.0 - p, point_val.1, false)
+            (one - p, point_val.1, false)
         };
 
         // Decide between point (0, 0, 1) and (1, 1, 0) as closest
@@ -230,15 +388,15 @@ impl OpenSimplexNoise3D {
         )
     }
 
-    fn determine_further_side(ins: Vec3<f64>) -> (Vec2<bool>, Vec2<i32>) {
-        let (score, mut point, mut is_further_side) =
-        Self::decide_between_points(ins);
+    fn determine_further_side<F: Float>(ins: Vec3<F>) -> (Vec2<bool>, Vec2<i32>) {
+        let (score, mut point, mut is_further_side) = Self::decide_between_points(ins);
 
         // The closest out of the two (1, 0, 0) and (0, 1, 1) will replace
         // the furthest out of the two decided above, if closer.
         let p = ins.y + ins.z;
-        if p > 1.0 {
-            let score_value = p - 1.0;
+        let one = F::from_f64(1.0);
+        if p > one {
+            let score_value = p - one;
             if score.x <= score.y && score.x < score_value {
                 point.x = 6;
                 is_further_side.x = true;
@@ -247,7 +405,7 @@ impl OpenSimplexNoise3D {
                 is_further_side.y = true;
             }
         } else {
-            let score_value = 1.0 - p;
+            let score_value = one - p;
             if score.x <= score.y && score.x < score_value {
                 point.x = 1;
                 is_further_side.x = false;
@@ -260,12 +418,12 @@ impl OpenSimplexNoise3D {
         (is_further_side, point)
     }
 
-    fn determine_closest_point(
-        score: Vec2<f64>,
+    fn determine_closest_point<F: Float>(
+        score: Vec2<F>,
         point: Vec2<i64>,
         factor: Vec2<i64>,
-        ins: Vec3<f64>,
-    ) -> (Vec2<f64>, Vec2<i64>) {
+        ins: Vec3<F>,
+    ) -> (Vec2<F>, Vec2<i64>) {
         let mut score = score;
         let mut point = point;
         if ins.x >= ins.y && ins.z > ins.y {
@@ -279,13 +437,13 @@ impl OpenSimplexNoise3D {
         (score, point)
     }
 
-    fn determine_lattice_points_including_0_0_0(
-        in_sum: f64,
-        score: Vec2<f64>,
+    fn determine_lattice_points_including_0_0_0<F: Float, R: Contribution<F>>(
+        in_sum: F,
+        score: Vec2<F>,
         point: Vec2<i64>,
-        contribute: impl Fn(f64, f64, f64) -> f64,
-    ) -> f64 {
-        let wins = 1.0 - in_sum;
+        contribute: impl Fn(f64, f64, f64) -> R,
+    ) -> R {
+        let wins = F::from_f64(1.0) - in_sum;
 
         if wins > score.x || wins > score.y {
             // (0, 0, 0) is one of the closest two tetrahedral vertices.
@@ -308,13 +466,13 @@ impl OpenSimplexNoise3D {
         }
     }
 
-    fn determine_lattice_points_including_1_1_1(
-        in_sum: f64,
-        score: Vec2<f64>,
+    fn determine_lattice_points_including_1_1_1<F: Float, R: Contribution<F>>(
+        in_sum: F,
+        score: Vec2<F>,
         point: Vec2<i64>,
-        contribute: impl Fn(f64, f64, f64) -> f64,
-    ) -> f64 {
-        let wins = 3.0 - in_sum;
+        contribute: impl Fn(f64, f64, f64) -> R,
+    ) -> R {
+        let wins = F::from_f64(3.0) - in_sum;
         if wins < score.x || wins < score.y {
             // (1, 1, 1) is one of the closest two tetrahedral vertices.
             // Our other closest vertex is the closest out of a and b.
@@ -335,10 +493,14 @@ impl OpenSimplexNoise3D {
             }
         }
     }
+}
 
-    fn get_grad_table_index(grid: Vec3<f64>, perm: &PermTable) -> usize {
-        let index0 = ((perm[(grid.x as i64 & 0xFF) as usize] + grid.y as i64) & 0xFF) as usize;
-        let index1 = ((perm[index0] + grid.z as i64) & 0xFF) as usize;
-        perm[index1] as usize % GRAD_TABLE.len()
+impl<F> GradientIndex for Vec3<F> {
+    fn from_hash(hash: i64) -> usize {
+        grad_index_3d(hash)
     }
 }
+
+pub(crate) const fn grad_index_3d(hash: i64) -> usize {
+    hash as usize % GRAD_TABLE.len()
+}
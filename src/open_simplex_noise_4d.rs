@@ -1,16 +1,18 @@
 use super::{
-    utils,
+    float::Float,
+    hash::{GradientIndex, LatticeHash},
+    utils::{self, Contribution},
     vector::{vec4::Vec4, VecMethods},
-    NoiseEvaluator, PermTable,
+    NoiseEvaluator, NoiseVersion,
 };
 use crate::vector::vec2::Vec2;
 
 const STRETCH: f64 = -0.138_196_601_125_011; // (1 / sqrt(4 + 1) - 1) / 4
 const SQUISH: f64 = 0.309_016_994_374_947; // (sqrt(4 + 1) - 1) / 4
 
-const NORMALIZING_SCALAR: f64 = 30.0;
+pub(crate) const NORMALIZING_SCALAR: f64 = 30.0;
 
-const GRAD_TABLE: [Vec4<f64>; 64] = [
+pub(crate) const GRAD_TABLE: [Vec4<f64>; 64] = [
     Vec4::new(3.0, 1.0, 1.0, 1.0),
     Vec4::new(1.0, 3.0, 1.0, 1.0),
     Vec4::new(1.0, 1.0, 3.0, 1.0),
@@ -77,35 +79,164 @@ const GRAD_TABLE: [Vec4<f64>; 64] = [
     Vec4::new(-1.0, -1.0, -1.0, -3.0),
 ];
 
+// Every lattice point that can be in range of a point of the cell, for `ins` sorted in
+// decreasing order: the vertices of its region first, then the points around them. The
+// regions are the pentachoron at (0, 0, 0, 0), the two dispentachorons and the pentachoron
+// at (1, 1, 1, 1).
+pub(crate) const LATTICE_POINTS: [&[[f64; 4]]; 4] = [
+    &[
+        [0.0, 0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+        [1.0, 1.0, 0.0, 0.0],
+        [1.0, 0.0, 1.0, 0.0],
+        [1.0, 0.0, 0.0, 1.0],
+        [1.0, 1.0, -1.0, 0.0],
+        [1.0, 1.0, 0.0, -1.0],
+        [1.0, 0.0, 1.0, -1.0],
+        [1.0, -1.0, 0.0, 0.0],
+        [1.0, 0.0, -1.0, 0.0],
+        [1.0, 0.0, 0.0, -1.0],
+        [0.0, 1.0, -1.0, 0.0],
+        [0.0, 1.0, 0.0, -1.0],
+        [0.0, 0.0, 1.0, -1.0],
+    ],
+    &[
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+        [1.0, 1.0, 0.0, 0.0],
+        [1.0, 0.0, 1.0, 0.0],
+        [1.0, 0.0, 0.0, 1.0],
+        [0.0, 1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0, 1.0],
+        [0.0, 0.0, 1.0, 1.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [1.0, 1.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0, 1.0],
+        [1.0, 0.0, 1.0, 1.0],
+        [2.0, 0.0, 0.0, 0.0],
+        [1.0, 1.0, -1.0, 0.0],
+        [1.0, 1.0, 0.0, -1.0],
+        [1.0, 0.0, 1.0, -1.0],
+        [0.0, 1.0, 1.0, -1.0],
+        [1.0, 1.0, 1.0, -1.0],
+    ],
+    &[
+        [1.0, 1.0, 0.0, 0.0],
+        [1.0, 0.0, 1.0, 0.0],
+        [1.0, 0.0, 0.0, 1.0],
+        [0.0, 1.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0, 1.0],
+        [0.0, 0.0, 1.0, 1.0],
+        [1.0, 1.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0, 1.0],
+        [1.0, 0.0, 1.0, 1.0],
+        [0.0, 1.0, 1.0, 1.0],
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [2.0, 0.0, 0.0, 0.0],
+        [2.0, 1.0, 0.0, 0.0],
+        [2.0, 0.0, 1.0, 0.0],
+        [2.0, 0.0, 0.0, 1.0],
+        [1.0, 2.0, 0.0, 0.0],
+        [1.0, 1.0, 1.0, -1.0],
+    ],
+    &[
+        [1.0, 1.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0, 1.0],
+        [1.0, 0.0, 1.0, 1.0],
+        [0.0, 1.0, 1.0, 1.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [1.0, 1.0, 0.0, 0.0],
+        [1.0, 0.0, 1.0, 0.0],
+        [0.0, 1.0, 1.0, 0.0],
+        [2.0, 1.0, 1.0, 0.0],
+        [2.0, 1.0, 0.0, 1.0],
+        [2.0, 0.0, 1.0, 1.0],
+        [1.0, 2.0, 1.0, 0.0],
+        [1.0, 2.0, 0.0, 1.0],
+        [1.0, 1.0, 2.0, 0.0],
+        [2.0, 1.0, 0.0, 0.0],
+        [2.0, 0.0, 1.0, 0.0],
+        [1.0, 2.0, 0.0, 0.0],
+    ],
+];
+
 pub struct OpenSimplexNoise4D {}
 
-impl NoiseEvaluator<Vec4<f64>> for OpenSimplexNoise4D {
-    const STRETCH_POINT: Vec4<f64> = Vec4::new(STRETCH, STRETCH, STRETCH, STRETCH);
-    const SQUISH_POINT: Vec4<f64> = Vec4::new(SQUISH, SQUISH, SQUISH, SQUISH);
+impl<F: Float> NoiseEvaluator<Vec4<F>, F> for OpenSimplexNoise4D {
+    fn stretch_point() -> Vec4<F> {
+        Self::STRETCH_POINT.map(F::from_f64)
+    }
 
-    fn extrapolate(grid: Vec4<f64>, delta: Vec4<f64>, perm: &PermTable) -> f64 {
-        let point = GRAD_TABLE[Self::get_grad_table_index(grid, perm)];
+    fn squish_point() -> Vec4<F> {
+        Self::SQUISH_POINT.map(F::from_f64)
+    }
 
-        point.x * delta.x + point.y * delta.y + point.z * delta.z + point.w * delta.w
+    fn extrapolate<H: LatticeHash<Vec4<F>>>(grid: Vec4<F>, delta: Vec4<F>, perm: &H) -> F {
+        Self::get_grad(grid, perm).dot(&delta)
     }
 
-    fn eval(input: Vec4<f64>, perm: &PermTable) -> f64 {
-        let stretch: Vec4<f64> = input + (Self::STRETCH_POINT * input.sum());
-        let grid = stretch.map(utils::floor).map(utils::to_f64);
+    fn get_grad<H: LatticeHash<Vec4<F>>>(grid: Vec4<F>, perm: &H) -> Vec4<F> {
+        GRAD_TABLE[perm.gradient_index(grid)].map(F::from_f64)
+    }
 
-        let squashed: Vec4<f64> = grid + (Self::SQUISH_POINT * grid.sum());
-        let ins = stretch - grid;
-        let origin = input - squashed;
+    fn eval<H: LatticeHash<Vec4<F>>>(input: Vec4<F>, perm: &H, version: NoiseVersion) -> F {
+        Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute::<Self, F, Vec4<F>, H>,
+        )
+    }
 
-        Self::get_value(grid, origin, ins, perm)
+    fn eval_with_gradient<H: LatticeHash<Vec4<F>>>(
+        input: Vec4<F>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> (F, Vec4<F>) {
+        let result = Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute_with_gradient::<Self, F, Vec4<F>, H>,
+        );
+        (result.value, result.gradient)
     }
 }
 
 impl OpenSimplexNoise4D {
-    fn inside_pentachoron_at_0_0_0_0(
-        ins: Vec4<f64>,
-        contribute: impl Fn(f64, f64, f64, f64) -> f64,
-    ) -> f64 {
+    pub(crate) const STRETCH_POINT: Vec4<f64> = Vec4::new(STRETCH, STRETCH, STRETCH, STRETCH);
+    pub(crate) const SQUISH_POINT: Vec4<f64> = Vec4::new(SQUISH, SQUISH, SQUISH, SQUISH);
+
+    // Walks the lattice points around `input`, summing what `contribution` returns for each of
+    // them. Passing `contribute::<E, ...>` evaluates the noise with the gradients of `E`.
+    pub fn evaluate<F: Float, R: Contribution<F>, H: LatticeHash<Vec4<F>>>(
+        input: Vec4<F>,
+        perm: &H,
+        version: NoiseVersion,
+        contribution: impl Fn(Vec4<F>, Vec4<F>, Vec4<F>, &H) -> R,
+    ) -> R {
+        let stretch: Vec4<F> = input + (Self::stretch_point() * input.sum());
+        let grid = stretch.map(utils::floor).map(F::from_i64);
+
+        let squashed: Vec4<F> = grid + (Self::squish_point() * grid.sum());
+        let ins = stretch - grid;
+        let origin = input - squashed;
+
+        Self::get_value(grid, origin, ins, perm, version, contribution)
+    }
+
+    fn inside_pentachoron_at_0_0_0_0<F: Float, R: Contribution<F>>(
+        ins: Vec4<F>,
+        contribute: impl Fn(f64, f64, f64, f64) -> R,
+    ) -> R {
         let mut point = Vec2::new(1, 2);
         let mut score = ins;
         if score.x >= score.y && ins.z > score.y {
@@ -125,7 +256,7 @@ impl OpenSimplexNoise4D {
 
         // Now we determine the three lattice points not part of the pentachoron that may contribute.
         // This depends on the closest two pentachoron vertices, including (0, 0, 0, 0)
-        let uins = 1.0 - ins.sum();
+        let uins = F::from_f64(1.0) - ins.sum();
         let value = if uins > score.x || uins > score.y {
             // (0, 0, 0, 0) is one of the closest two pentachoron vertices.
             // Our other closest vertex is the closest out of a and b.
@@ -200,10 +331,10 @@ impl OpenSimplexNoise4D {
             + contribute(0.0, 0.0, 0.0, 1.0)
     }
 
-    fn inside_pentachoron_at_1_1_1_1(
-        ins: Vec4<f64>,
-        contribute: impl Fn(f64, f64, f64, f64) -> f64,
-    ) -> f64 {
+    fn inside_pentachoron_at_1_1_1_1<F: Float, R: Contribution<F>>(
+        ins: Vec4<F>,
+        contribute: impl Fn(f64, f64, f64, f64) -> R,
+    ) -> R {
         let mut point = Vec2::new(14, 13);
         let mut score = ins;
         if score.x <= score.y && ins.z < score.y {
@@ -222,10 +353,11 @@ impl OpenSimplexNoise4D {
         }
 
         // Now we determine the three lattice points not part of the pentachoron that may contribute.
-        // This depends on the closest two pentachoron vertices, including (0, 0, 0, 0)
-        let uins = 1.0 - ins.sum();
+        // This depends on the closest two pentachoron vertices, including (1, 1, 1, 1)
+        // 0.3 releases measure the distance from (0, 0, 0, 0) here
+        let uins = F::from_f64(1.0) - ins.sum();
         let value = if uins < score.x || uins > score.y {
-            // (0, 0, 0, 0) is one of the closest two pentachoron vertices.
+            // (1, 1, 1, 1) is one of the closest two pentachoron vertices.
             // Our other closest vertex is the closest out of a and b.
             let closest = if score.y < score.x { point.y } else { point.x };
             match closest {
@@ -252,7 +384,7 @@ impl OpenSimplexNoise4D {
                 }
             }
         } else {
-            // (0, 0, 0, 0) is not one of the closest two pentachoron vertices.
+            // (1, 1, 1, 1) is not one of the closest two pentachoron vertices.
             // Our three extra vertices are determined by the closest two.
             let closest = point.x & point.y;
             match closest {
@@ -298,13 +430,13 @@ impl OpenSimplexNoise4D {
             + contribute(1.0, 1.0, 1.0, 1.0)
     }
 
-    fn inside_second_dispentachoron(
-        ins: Vec4<f64>,
-        contribute: impl Fn(f64, f64, f64, f64) -> f64,
-    ) -> f64 {
-        let mut value = 0.0;
+    fn inside_second_dispentachoron<F: Float, R: Contribution<F>>(
+        ins: Vec4<F>,
+        contribute: impl Fn(f64, f64, f64, f64) -> R,
+    ) -> R {
+        let mut value = R::default();
 
-        let mut score = Vec2::new(0.0, 0.0);
+        let mut score = Vec2::new(F::from_f64(0.0), F::from_f64(0.0));
         let mut point = Vec2::new(0, 0);
         let mut is_bigger_side = Vec2::new(true, true);
 
@@ -349,7 +481,7 @@ impl OpenSimplexNoise4D {
         }
 
         // Decide if (0, 1, 1, 1) is closer.
-        let p1 = 3.0 - ins.sum() + ins.x;
+        let p1 = F::from_f64(3.0) - ins.sum() + ins.x;
         if score.x <= score.y && p1 < score.y {
             score.y = p1;
             point.y = 14;
@@ -361,7 +493,7 @@ impl OpenSimplexNoise4D {
         }
 
         // Decide if (1, 0, 1, 1) is closer.
-        let p2 = 3.0 - ins.sum() + ins.y;
+        let p2 = F::from_f64(3.0) - ins.sum() + ins.y;
         if score.x <= score.y && p2 < score.y {
             score.y = p2;
             point.y = 13;
@@ -373,7 +505,7 @@ impl OpenSimplexNoise4D {
         }
 
         // Decide if (1, 1, 0, 1) is closer.
-        let p3 = 3.0 - ins.sum() + ins.z;
+        let p3 = F::from_f64(3.0) - ins.sum() + ins.z;
         if score.x <= score.y && p3 < score.y {
             score.y = p3;
             point.y = 11;
@@ -385,7 +517,7 @@ impl OpenSimplexNoise4D {
         }
 
         // Decide if (1, 1, 1, 0) is closer.
-        let p4 = 3.0 - ins.sum() + ins.w;
+        let p4 = F::from_f64(3.0) - ins.sum() + ins.w;
         if score.x <= score.y && p4 < score.y {
             point.y = 7;
             is_bigger_side.y = false;
@@ -450,7 +582,7 @@ impl OpenSimplexNoise4D {
                 9 => contribute(2.0, 0.0, 0.0, 1.0) + contribute(1.0, 0.0, 0.0, 2.0),
                 10 => contribute(0.0, 2.0, 0.0, 1.0) + contribute(0.0, 1.0, 0.0, 2.0),
                 12 => contribute(0.0, 0.0, 2.0, 1.0) + contribute(0.0, 0.0, 1.0, 2.0),
-                _ => 0.0,
+                _ => R::default(),
             };
 
             // One contribution is a permutation of (1, 1, 1, -1) based on the smaller-sided point
@@ -475,13 +607,13 @@ impl OpenSimplexNoise4D {
             + contribute(0.0, 0.0, 1.0, 1.0)
     }
 
-    fn inside_first_dispentachoron(
-        ins: Vec4<f64>,
-        contribute: impl Fn(f64, f64, f64, f64) -> f64,
-    ) -> f64 {
-        let mut value = 0.0;
+    fn inside_first_dispentachoron<F: Float, R: Contribution<F>>(
+        ins: Vec4<F>,
+        contribute: impl Fn(f64, f64, f64, f64) -> R,
+    ) -> R {
+        let mut value = R::default();
 
-        let mut score = Vec2::new(0.0, 0.0);
+        let mut score = Vec2::new(F::from_f64(0.0), F::from_f64(0.0));
         let mut point = Vec2::new(0, 0);
         let mut is_bigger_side = Vec2::new(true, true);
 
@@ -525,7 +657,7 @@ impl OpenSimplexNoise4D {
         }
 
         // Decide if (1, 0, 0, 0) is closer.
-        let p1 = 2.0 - ins.sum() + ins.x;
+        let p1 = F::from_f64(2.0) - ins.sum() + ins.x;
         if score.x >= score.y && p1 > score.y {
             score.y = p1;
             point.y = 0x01;
@@ -537,7 +669,7 @@ impl OpenSimplexNoise4D {
         }
 
         // Decide if (0, 1, 0, 0) is closer.
-        let p2 = 2.0 - ins.sum() + ins.y;
+        let p2 = F::from_f64(2.0) - ins.sum() + ins.y;
         if score.x >= score.y && p2 > score.y {
             score.y = p2;
             point.y = 0x02;
@@ -549,7 +681,7 @@ impl OpenSimplexNoise4D {
         }
 
         // Decide if (0, 0, 1, 0) is closer.
-        let p3 = 2.0 - ins.sum() + ins.z;
+        let p3 = F::from_f64(2.0) - ins.sum() + ins.z;
         if score.x >= score.y && p3 > score.y {
             score.y = p3;
             point.y = 0x04;
@@ -561,7 +693,7 @@ impl OpenSimplexNoise4D {
         }
 
         // Decide if (0, 0, 0, 1) is closer.
-        let p4 = 2.0 - ins.sum() + ins.w;
+        let p4 = F::from_f64(2.0) - ins.sum() + ins.w;
         if score.x >= score.y && p4 > score.y {
             point.y = 0x08;
             is_bigger_side.y = false;
@@ -623,7 +755,7 @@ impl OpenSimplexNoise4D {
                 9 => contribute(1.0, -1.0, 0.0, 1.0) + contribute(1.0, 0.0, -1.0, 1.0),
                 10 => contribute(-1.0, 1.0, 0.0, 1.0) + contribute(0.0, 1.0, -1.0, 1.0),
                 12 => contribute(-1.0, 0.0, 1.0, 1.0) + contribute(0.0, -1.0, 1.0, 1.0),
-                _ => 0.0,
+                _ => R::default(),
             };
 
             // One contribution is a permutation of (0, 0, 0, 2) based on the smaller-sided point
@@ -648,25 +780,31 @@ impl OpenSimplexNoise4D {
             + contribute(0.0, 0.0, 1.0, 1.0)
     }
 
-    fn get_value(grid: Vec4<f64>, origin: Vec4<f64>, ins: Vec4<f64>, perm: &PermTable) -> f64 {
+    fn get_value<F: Float, R: Contribution<F>, H: LatticeHash<Vec4<F>>>(
+        grid: Vec4<F>,
+        origin: Vec4<F>,
+        ins: Vec4<F>,
+        perm: &H,
+        version: NoiseVersion,
+        contribution: impl Fn(Vec4<F>, Vec4<F>, Vec4<F>, &H) -> R,
+    ) -> R {
         let contribute = |x: f64, y: f64, z: f64, w: f64| {
-            utils::contribute::<OpenSimplexNoise4D, Vec4<f64>>(
-                Vec4::new(x, y, z, w),
-                origin,
-                grid,
-                perm,
-            )
+            contribution(Vec4::new(x, y, z, w).map(F::from_f64), origin, grid, perm)
         };
 
+        if version != NoiseVersion::V0_3 {
+            return Self::sum_lattice_points(ins, contribute) / F::from_f64(NORMALIZING_SCALAR);
+        }
+
         // Sum those together to get a value that determines the region.
         let in_sum = ins.sum();
-        let value = if in_sum <= 1.0 {
+        let value = if in_sum <= F::from_f64(1.0) {
             // We're inside the pentachoron (4-Simplex) at (0,0,0,0)
             Self::inside_pentachoron_at_0_0_0_0(ins, contribute)
-        } else if in_sum >= 3.0 {
+        } else if in_sum >= F::from_f64(3.0) {
             // We're inside the pentachoron (4-Simplex) at (1, 1, 1, 1)
             Self::inside_pentachoron_at_1_1_1_1(ins, contribute)
-        } else if in_sum <= 2.0 {
+        } else if in_sum <= F::from_f64(2.0) {
             // We're inside the first dispentachoron (Rectified 4-Simplex)
             Self::inside_first_dispentachoron(ins, contribute)
         } else {
@@ -674,12 +812,51 @@ impl OpenSimplexNoise4D {
             Self::inside_second_dispentachoron(ins, contribute)
         };
 
-        value / NORMALIZING_SCALAR
+        value / F::from_f64(NORMALIZING_SCALAR)
+    }
+
+    // Same as `OpenSimplexNoise3D::sum_lattice_points`
+    fn sum_lattice_points<F: Float, R: Contribution<F>>(
+        ins: Vec4<F>,
+        contribute: impl Fn(f64, f64, f64, f64) -> R,
+    ) -> R {
+        Self::lattice_points(ins).fold(R::default(), |value, point| {
+            value + contribute(point.x, point.y, point.z, point.w)
+        })
+    }
+
+    // Same as `OpenSimplexNoise3D::lattice_points`, with the 4D regions.
+    pub(crate) fn lattice_points<F: Float>(ins: Vec4<F>) -> impl Iterator<Item = Vec4<f64>> {
+        // Position of each coordinate in decreasing order, ties going to the first axis
+        let rank = Vec4::new(
+            usize::from(ins.y > ins.x) + usize::from(ins.z > ins.x) + usize::from(ins.w > ins.x),
+            usize::from(ins.x >= ins.y) + usize::from(ins.z > ins.y) + usize::from(ins.w > ins.y),
+            usize::from(ins.x >= ins.z) + usize::from(ins.y >= ins.z) + usize::from(ins.w > ins.z),
+            usize::from(ins.x >= ins.w) + usize::from(ins.y >= ins.w) + usize::from(ins.z >= ins.w),
+        );
+        let in_sum = ins.sum();
+        let region = if in_sum <= F::from_f64(1.0) {
+            0
+        } else if in_sum >= F::from_f64(3.0) {
+            3
+        } else if in_sum <= F::from_f64(2.0) {
+            1
+        } else {
+            2
+        };
+
+        LATTICE_POINTS[region]
+            .iter()
+            .map(move |point| Vec4::new(point[rank.x], point[rank.y], point[rank.z], point[rank.w]))
     }
+}
 
-    fn get_grad_table_index(grid: Vec4<f64>, perm: &PermTable) -> usize {
-        let index0 = ((perm[(grid.x as i64 & 0xFF) as usize] + grid.y as i64) & 0xFF) as usize;
-        let index1 = ((perm[index0] + grid.z as i64) & 0xFF) as usize;
-        ((perm[((perm[index1] + grid.w as i64) & 0xFF) as usize] & 0xFC) >> 2) as usize
+impl<F> GradientIndex for Vec4<F> {
+    fn from_hash(hash: i64) -> usize {
+        grad_index_4d(hash)
     }
 }
+
+pub(crate) const fn grad_index_4d(hash: i64) -> usize {
+    ((hash & 0xFC) >> 2) as usize
+}
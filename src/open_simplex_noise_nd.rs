@@ -0,0 +1,160 @@
+use super::float::Float;
+use super::hash::{GradientIndex, LatticeHash};
+use super::utils::{self, Contribution};
+use super::vector::{vecn::VecN, VecMethods};
+use super::{NoiseEvaluator, NoiseVersion};
+
+// Same as the radius of `utils::contribute`
+const RADIUS_SQUARED: f64 = 2.0;
+
+// OpenSimplex noise of any dimension from 2 to 6, on the same stretched lattice as
+// `OpenSimplexNoise2D` to `OpenSimplexNoise4D`. Instead of their hand written regions, the
+// contributing lattice points are found by searching the offsets from the point's cell.
+pub struct OpenSimplexNoiseND<const N: usize> {}
+
+impl<const N: usize> OpenSimplexNoiseND<N> {
+    // (1 / sqrt(N + 1) - 1) / N
+    const STRETCH: f64 = match N {
+        2 => -0.211_324_865_405_187,
+        3 => -0.166_666_666_666_667,
+        4 => -0.138_196_601_125_011,
+        5 => -0.118_350_341_907_227,
+        6 => -0.103_672_587_831_795,
+        _ => panic!("OpenSimplexNoiseND supports 2 to 6 dimensions"),
+    };
+    // (sqrt(N + 1) - 1) / N
+    const SQUISH: f64 = match N {
+        2 => 0.366_025_403_784_439,
+        3 => 0.333_333_333_333_333,
+        4 => 0.309_016_994_374_947,
+        5 => 0.289_897_948_556_636,
+        6 => 0.274_291_885_177_432,
+        _ => panic!("OpenSimplexNoiseND supports 2 to 6 dimensions"),
+    };
+    // Largest sum reachable when every contributing lattice point gets the gradient pointing
+    // the most along its offset, found by maximizing over the cell and rounded up, so no seed
+    // leaves [-1, 1].
+    const NORMALIZING_SCALAR: f64 = match N {
+        2 => 7.064_83,
+        3 => 10.572_02,
+        4 => 13.446_74,
+        5 => 15.988_68,
+        6 => 18.455_39,
+        _ => panic!("OpenSimplexNoiseND supports 2 to 6 dimensions"),
+    };
+}
+
+impl<F: Float, const N: usize> NoiseEvaluator<VecN<F, N>, F> for OpenSimplexNoiseND<N> {
+    fn stretch_point() -> VecN<F, N> {
+        VecN::new([F::from_f64(Self::STRETCH); N])
+    }
+
+    fn squish_point() -> VecN<F, N> {
+        VecN::new([F::from_f64(Self::SQUISH); N])
+    }
+
+    fn extrapolate<H: LatticeHash<VecN<F, N>>>(grid: VecN<F, N>, delta: VecN<F, N>, perm: &H) -> F {
+        Self::get_grad(grid, perm).dot(&delta)
+    }
+
+    // The midpoints of the edges of the hypercube: zero along one axis and +-1 along the others.
+    fn get_grad<H: LatticeHash<VecN<F, N>>>(grid: VecN<F, N>, perm: &H) -> VecN<F, N> {
+        let index = perm.gradient_index(grid);
+        let zero_axis = index % N;
+        let mut signs = index / N;
+
+        let mut coords = [F::from_f64(0.0); N];
+        for (axis, coord) in coords.iter_mut().enumerate() {
+            if axis != zero_axis {
+                *coord = F::from_f64(if signs & 1 == 0 { 1.0 } else { -1.0 });
+                signs >>= 1;
+            }
+        }
+        VecN::new(coords)
+    }
+
+    fn eval<H: LatticeHash<VecN<F, N>>>(input: VecN<F, N>, perm: &H, version: NoiseVersion) -> F {
+        Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute::<Self, F, VecN<F, N>, H>,
+        )
+    }
+
+    fn eval_with_gradient<H: LatticeHash<VecN<F, N>>>(
+        input: VecN<F, N>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> (F, VecN<F, N>) {
+        let result = Self::evaluate(
+            input,
+            perm,
+            version,
+            utils::contribute_with_gradient::<Self, F, VecN<F, N>, H>,
+        );
+        (result.value, result.gradient)
+    }
+}
+
+impl<const N: usize> OpenSimplexNoiseND<N> {
+    // Walks the lattice points around `input`, summing what `contribution` returns for each of
+    // them. There is only one version of the N-dimensional noise, `version` is taken for
+    // symmetry with the other evaluators.
+    pub fn evaluate<F: Float, R: Contribution<F>, H: LatticeHash<VecN<F, N>>>(
+        input: VecN<F, N>,
+        perm: &H,
+        _version: NoiseVersion,
+        contribution: impl Fn(VecN<F, N>, VecN<F, N>, VecN<F, N>, &H) -> R,
+    ) -> R {
+        let stretch = input + (Self::stretch_point() * input.sum());
+        let grid = stretch.map(utils::floor).map(F::from_i64);
+
+        let squashed = grid + (Self::squish_point() * grid.sum());
+        let ins = stretch - grid;
+        let origin = input - squashed;
+
+        let contribute =
+            |delta: &[f64; N]| contribution(VecN::new(*delta).map(F::from_f64), origin, grid, perm);
+        let value = Self::sum_lattice_points(&ins, &mut [0.0; N], 0, F::from_f64(0.0), &contribute);
+
+        value / F::from_f64(Self::NORMALIZING_SCALAR)
+    }
+
+    // Lattice points are summed axis by axis, offsets from the cell range over -1..=2 and those
+    // already out of reach along the axes picked so far are skipped. For a stretched offset `u`
+    // the squared distance in input space is |u|^2 + (sum of u)^2, so |u|^2 alone is enough
+    // to leave a point out.
+    fn sum_lattice_points<F: Float, R: Contribution<F>>(
+        ins: &VecN<F, N>,
+        offset: &mut [f64; N],
+        axis: usize,
+        distance: F,
+        contribute: &impl Fn(&[f64; N]) -> R,
+    ) -> R {
+        if axis == N {
+            return contribute(offset);
+        }
+
+        let mut value = R::default();
+        for &step in &[-1.0, 0.0, 1.0, 2.0] {
+            let delta = ins.coords[axis] - F::from_f64(step);
+            let distance = distance + delta * delta;
+            if distance < F::from_f64(RADIUS_SQUARED) {
+                offset[axis] = step;
+                value += Self::sum_lattice_points(ins, offset, axis + 1, distance, contribute);
+            }
+        }
+        value
+    }
+}
+
+// Outside of 2D and 4D the N * 2^(N - 1) gradients don't divide the 2048 entries of the
+// permutation table, so like the 24 gradients of `OpenSimplexNoise3D` some come up once more per
+// table than others: 26 against 25 times in 5D, 11 against 10 in 6D. The 32 bit hashes of the
+// other periods leave a negligible bias.
+impl<F, const N: usize> GradientIndex for VecN<F, N> {
+    fn from_hash(hash: i64) -> usize {
+        hash as usize % (N << (N - 1))
+    }
+}
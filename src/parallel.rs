@@ -0,0 +1,65 @@
+use rayon::prelude::*;
+
+use super::{OpenSimplexGenerator, PointHash, VolumeLayout};
+
+// Parallel versions of the fill functions. Rows, and lines of volumes, are spread across
+// the rayon thread pool, each of them being evaluated exactly like by the sequential path.
+impl<H: PointHash + Sync> OpenSimplexGenerator<H> {
+    pub fn par_fill_2d(
+        &self,
+        buf: &mut [f64],
+        width: usize,
+        height: usize,
+        origin: [f64; 2],
+        step: f64,
+    ) {
+        assert_eq!(
+            buf.len(),
+            width * height,
+            "buffer doesn't match the grid size"
+        );
+        buf.par_chunks_mut(width.max(1))
+            .enumerate()
+            .for_each(|(j, row)| self.fill_row_2d(row, j, origin, step, |value| value));
+    }
+
+    pub fn par_fill_2d_f32(
+        &self,
+        buf: &mut [f32],
+        width: usize,
+        height: usize,
+        origin: [f64; 2],
+        step: f64,
+    ) {
+        assert_eq!(
+            buf.len(),
+            width * height,
+            "buffer doesn't match the grid size"
+        );
+        buf.par_chunks_mut(width.max(1))
+            .enumerate()
+            .for_each(|(j, row)| self.fill_row_2d(row, j, origin, step, |value| value as f32));
+    }
+
+    pub fn par_fill_3d(
+        &self,
+        buf: &mut [f64],
+        size: [usize; 3],
+        origin: [f64; 3],
+        step: f64,
+        layout: VolumeLayout,
+    ) {
+        assert_eq!(
+            buf.len(),
+            size[0] * size[1] * size[2],
+            "buffer doesn't match the volume size"
+        );
+
+        let axes = layout.axes();
+        buf.par_chunks_mut(size[axes[2]].max(1))
+            .enumerate()
+            .for_each(|(line_index, line)| {
+                self.fill_line_3d(line, line_index, size, origin, step, axes)
+            });
+    }
+}
@@ -0,0 +1,80 @@
+use super::hash::LatticeHash;
+use super::math;
+use super::open_simplex_noise_2d::OpenSimplexNoise2D;
+use super::open_simplex_noise_3d::OpenSimplexNoise3D;
+use super::vector::{vec2::Vec2, vec3::Vec3};
+use super::{OpenSimplexGenerator, PointHash};
+
+// Wraps the lattice coordinates before hashing them, so the gradients repeat every `period` cells.
+struct Periodic<'a, H, T> {
+    perm: &'a H,
+    period: T,
+}
+
+impl<H: LatticeHash<Vec2<f64>>> LatticeHash<Vec2<f64>> for Periodic<'_, H, Vec2<f64>> {
+    fn hash(&self, grid: Vec2<f64>) -> i64 {
+        self.perm.hash(Vec2::new(
+            math::rem_euclid(grid.x, self.period.x),
+            math::rem_euclid(grid.y, self.period.y),
+        ))
+    }
+}
+
+impl<H: LatticeHash<Vec3<f64>>> LatticeHash<Vec3<f64>> for Periodic<'_, H, Vec3<f64>> {
+    fn hash(&self, grid: Vec3<f64>) -> i64 {
+        self.perm.hash(Vec3::new(
+            math::rem_euclid(grid.x, self.period.x),
+            math::rem_euclid(grid.y, self.period.y),
+            math::rem_euclid(grid.z, self.period.z),
+        ))
+    }
+}
+
+impl<H: PointHash> OpenSimplexGenerator<H> {
+    // Unlike `eval_2d`, `x` and `y` are coordinates in the stretched lattice space of the noise,
+    // where the lattice is axis aligned, and `period` counts lattice cells: the output tiles
+    // exactly every `period` units along each axis. The pattern looks sheared along the main
+    // diagonal compared to `eval_2d`, and the same `(x, y)` gives a different value. Use
+    // `eval_2d_torus` to tile noise sampled from world space coordinates.
+    //
+    // Panics if a period is longer than the `HashPeriod`, whose gradients would already repeat
+    // inside the tile.
+    pub fn eval_2d_periodic(&self, x: f64, y: f64, period: [u32; 2]) -> f64 {
+        check_period(&period, LatticeHash::<Vec2<f64>>::repeats_after(self.lattice_hash()));
+
+        let periodic = Periodic {
+            perm: self.lattice_hash(),
+            period: Vec2::new(period[0] as f64, period[1] as f64),
+        };
+        OpenSimplexNoise2D::eval_stretched(Vec2::new(x, y), &periodic, self.version)
+    }
+
+    // Like `eval_2d_periodic`, `x`, `y` and `z` are coordinates in the stretched lattice space
+    // of the noise, not the world space coordinates `eval_3d` takes, and `period` counts lattice
+    // cells. The output tiles exactly every `period` units along each axis, but looks sheared
+    // along the main diagonal and gives a different value than `eval_3d` for the same point.
+    //
+    // Panics if a period is longer than the `HashPeriod`.
+    pub fn eval_3d_periodic(&self, x: f64, y: f64, z: f64, period: [u32; 3]) -> f64 {
+        check_period(&period, LatticeHash::<Vec3<f64>>::repeats_after(self.lattice_hash()));
+
+        let periodic = Periodic {
+            perm: self.lattice_hash(),
+            period: Vec3::new(period[0] as f64, period[1] as f64, period[2] as f64),
+        };
+        OpenSimplexNoise3D::eval_stretched(Vec3::new(x, y, z), &periodic, self.version)
+    }
+}
+
+// The wrapped coordinates are hashed as they are, any period up to the one of the hash tiles
+// exactly, even when it doesn't divide it.
+fn check_period(period: &[u32], hash_cells: Option<u32>) {
+    assert!(period.iter().all(|&p| p > 0), "period has to be positive");
+    if let Some(cells) = hash_cells {
+        assert!(
+            period.iter().all(|&p| p <= cells),
+            "period is longer than the hash period of {} cells",
+            cells
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use super::hash::LatticeHash;
+use super::open_simplex_noise_1d::OpenSimplexNoise1D;
+use super::open_simplex_noise_2d::OpenSimplexNoise2D;
+use super::open_simplex_noise_3d::OpenSimplexNoise3D;
+use super::open_simplex_noise_4d::OpenSimplexNoise4D;
+use super::open_simplex_noise_nd::OpenSimplexNoiseND;
+use super::vector::{vec1::Vec1, vec2::Vec2, vec3::Vec3, vec4::Vec4, vecn::VecN};
+use super::{NoiseEvaluator, NoiseVersion};
+
+// Lattice hashes covering every dimension a `NoisePoint` can have.
+pub trait PointHash:
+    LatticeHash<Vec1<f64>>
+    + LatticeHash<Vec2<f64>>
+    + LatticeHash<Vec3<f64>>
+    + LatticeHash<Vec4<f64>>
+    + LatticeHash<VecN<f64, 5>>
+    + LatticeHash<VecN<f64, 6>>
+{
+}
+impl<
+        H: LatticeHash<Vec1<f64>>
+            + LatticeHash<Vec2<f64>>
+            + LatticeHash<Vec3<f64>>
+            + LatticeHash<Vec4<f64>>
+            + LatticeHash<VecN<f64, 5>>
+            + LatticeHash<VecN<f64, 6>>,
+    > PointHash for H
+{
+}
+
+// Points the noise can be evaluated at, whatever their dimension: `f64`, arrays of one to six
+// coordinates, tuples and `Vec2` to `Vec4`. Code generic over it works with `eval` and
+// `eval_with_gradient` of the generators. 1D to 4D points give the same values as `eval_1d`
+// to `eval_4d`, 5D and 6D ones the same as `eval_nd`.
+pub trait NoisePoint: Copy {
+    type Gradient;
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64;
+    fn eval_with_gradient<H: PointHash>(
+        self,
+        hash: &H,
+        version: NoiseVersion,
+    ) -> (f64, Self::Gradient);
+}
+
+impl NoisePoint for f64 {
+    type Gradient = f64;
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        OpenSimplexNoise1D::eval(Vec1::new(self), hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, f64) {
+        let (value, gradient) =
+            OpenSimplexNoise1D::eval_with_gradient(Vec1::new(self), hash, version);
+        (value, gradient.x)
+    }
+}
+
+impl NoisePoint for [f64; 1] {
+    type Gradient = [f64; 1];
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        self[0].eval(hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, [f64; 1]) {
+        let (value, gradient) = self[0].eval_with_gradient(hash, version);
+        (value, [gradient])
+    }
+}
+
+impl NoisePoint for Vec2<f64> {
+    type Gradient = Vec2<f64>;
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        OpenSimplexNoise2D::eval(self, hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, Vec2<f64>) {
+        OpenSimplexNoise2D::eval_with_gradient(self, hash, version)
+    }
+}
+
+impl NoisePoint for [f64; 2] {
+    type Gradient = [f64; 2];
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        Vec2::new(self[0], self[1]).eval(hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, [f64; 2]) {
+        let (value, gradient) = Vec2::new(self[0], self[1]).eval_with_gradient(hash, version);
+        (value, [gradient.x, gradient.y])
+    }
+}
+
+impl NoisePoint for (f64, f64) {
+    type Gradient = [f64; 2];
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        [self.0, self.1].eval(hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, [f64; 2]) {
+        [self.0, self.1].eval_with_gradient(hash, version)
+    }
+}
+
+impl NoisePoint for Vec3<f64> {
+    type Gradient = Vec3<f64>;
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        OpenSimplexNoise3D::eval(self, hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, Vec3<f64>) {
+        OpenSimplexNoise3D::eval_with_gradient(self, hash, version)
+    }
+}
+
+impl NoisePoint for [f64; 3] {
+    type Gradient = [f64; 3];
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        Vec3::new(self[0], self[1], self[2]).eval(hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, [f64; 3]) {
+        let (value, gradient) =
+            Vec3::new(self[0], self[1], self[2]).eval_with_gradient(hash, version);
+        (value, [gradient.x, gradient.y, gradient.z])
+    }
+}
+
+impl NoisePoint for (f64, f64, f64) {
+    type Gradient = [f64; 3];
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        [self.0, self.1, self.2].eval(hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, [f64; 3]) {
+        [self.0, self.1, self.2].eval_with_gradient(hash, version)
+    }
+}
+
+impl NoisePoint for Vec4<f64> {
+    type Gradient = Vec4<f64>;
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        OpenSimplexNoise4D::eval(self, hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, Vec4<f64>) {
+        OpenSimplexNoise4D::eval_with_gradient(self, hash, version)
+    }
+}
+
+impl NoisePoint for [f64; 4] {
+    type Gradient = [f64; 4];
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        Vec4::new(self[0], self[1], self[2], self[3]).eval(hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, [f64; 4]) {
+        let (value, gradient) =
+            Vec4::new(self[0], self[1], self[2], self[3]).eval_with_gradient(hash, version);
+        (value, [gradient.x, gradient.y, gradient.z, gradient.w])
+    }
+}
+
+impl NoisePoint for (f64, f64, f64, f64) {
+    type Gradient = [f64; 4];
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        [self.0, self.1, self.2, self.3].eval(hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, [f64; 4]) {
+        [self.0, self.1, self.2, self.3].eval_with_gradient(hash, version)
+    }
+}
+
+impl NoisePoint for [f64; 5] {
+    type Gradient = [f64; 5];
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        OpenSimplexNoiseND::eval(VecN::new(self), hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, [f64; 5]) {
+        let (value, gradient) =
+            OpenSimplexNoiseND::eval_with_gradient(VecN::new(self), hash, version);
+        (value, gradient.coords)
+    }
+}
+
+impl NoisePoint for [f64; 6] {
+    type Gradient = [f64; 6];
+
+    fn eval<H: PointHash>(self, hash: &H, version: NoiseVersion) -> f64 {
+        OpenSimplexNoiseND::eval(VecN::new(self), hash, version)
+    }
+
+    fn eval_with_gradient<H: PointHash>(self, hash: &H, version: NoiseVersion) -> (f64, [f64; 6]) {
+        let (value, gradient) =
+            OpenSimplexNoiseND::eval_with_gradient(VecN::new(self), hash, version);
+        (value, gradient.coords)
+    }
+}
@@ -0,0 +1,58 @@
+use super::{PermTable, PSIZE};
+
+// How the permutation table is shuffled from a seed. `Legacy` reproduces tables generated
+// by the 0.3 releases, which derived every swap from the same LCG value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SeedAlgorithm {
+    Legacy,
+    // Advances the LCG before every swap. The tables don't match the Java reference: it shuffles
+    // 256 entries instead of 2048 and advances the seed three times before the first swap.
+    #[default]
+    AdvancingLcg,
+}
+
+impl SeedAlgorithm {
+    // Const, so generators can be built into statics at compile time.
+    pub(crate) const fn generate_perm_array(self, seed: i64) -> PermTable {
+        shuffle(self, seed)
+    }
+}
+
+const fn advance_lcg(seed: i64) -> i64 {
+    seed.wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407)
+}
+
+// Fisher-Yates shuffle of `0..PSIZE`, every swapped index is picked in `-i..=i` and wrapped.
+// The LCG is advanced on every step, except for `Legacy`.
+const fn shuffle(algorithm: SeedAlgorithm, seed: i64) -> PermTable {
+    let legacy_seed: i128 = (seed as i128 * 6_364_136_223_846_793_005) + 1_442_695_040_888_963_407;
+    let mut lcg_seed = seed;
+
+    let mut source = [0; PSIZE as usize];
+    let mut i = 0;
+    while i < PSIZE as usize {
+        source[i] = i as u16;
+        i += 1;
+    }
+
+    let mut perm = [0; PSIZE as usize];
+    let mut i = PSIZE;
+    while i > 0 {
+        i -= 1;
+        let mut r = match algorithm {
+            SeedAlgorithm::Legacy => ((legacy_seed + 31) % (i as i128 + 1)) as i64,
+            SeedAlgorithm::AdvancingLcg => {
+                lcg_seed = advance_lcg(lcg_seed);
+                lcg_seed.wrapping_add(31) % (i + 1)
+            }
+        };
+        if r < 0 {
+            r += i + 1;
+        }
+        perm[i as usize] = source[r as usize];
+        source[r as usize] = source[i as usize];
+    }
+
+    PermTable::new(perm)
+}
@@ -0,0 +1,95 @@
+use super::math;
+use super::{OpenSimplexGenerator, PointHash};
+
+// Sphere of `radius` world units, sampled with `frequency` noise units per world unit of its
+// surface. The noise is read from the 3D space the sphere sits in, so there are no seams along
+// any meridian and nothing gets pinched at the poles.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sphere {
+    pub radius: f64,
+    pub frequency: f64,
+}
+
+impl Sphere {
+    pub const fn new(radius: f64, frequency: f64) -> Self {
+        Self { radius, frequency }
+    }
+
+    fn noise_point(&self, direction: [f64; 3]) -> [f64; 3] {
+        let scale = self.radius * self.frequency;
+        [
+            direction[0] * scale,
+            direction[1] * scale,
+            direction[2] * scale,
+        ]
+    }
+}
+
+// Faces of a cube map, laid out as in OpenGL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CubeFace {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
+
+// Ways of addressing a point of the sphere. Y is the axis through the poles.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpherePoint {
+    // In radians, latitude from -PI / 2 at the south pole to PI / 2 at the north pole
+    LatLong { latitude: f64, longitude: f64 },
+    // `u` and `v` range over -1..=1 across the face
+    CubeFace { face: CubeFace, u: f64, v: f64 },
+    // Any non-zero vector, such as an icosphere vertex, projected onto the sphere
+    Direction([f64; 3]),
+}
+
+impl SpherePoint {
+    fn direction(self) -> [f64; 3] {
+        match self {
+            SpherePoint::LatLong {
+                latitude,
+                longitude,
+            } => {
+                let (sin_latitude, cos_latitude) = math::sin_cos(latitude);
+                let (sin_longitude, cos_longitude) = math::sin_cos(longitude);
+                [
+                    cos_latitude * cos_longitude,
+                    sin_latitude,
+                    cos_latitude * sin_longitude,
+                ]
+            }
+            SpherePoint::CubeFace { face, u, v } => normalize(match face {
+                CubeFace::PositiveX => [1.0, -v, -u],
+                CubeFace::NegativeX => [-1.0, -v, u],
+                CubeFace::PositiveY => [u, 1.0, v],
+                CubeFace::NegativeY => [u, -1.0, -v],
+                CubeFace::PositiveZ => [u, -v, 1.0],
+                CubeFace::NegativeZ => [-u, -v, -1.0],
+            }),
+            SpherePoint::Direction(direction) => normalize(direction),
+        }
+    }
+}
+
+fn normalize(vector: [f64; 3]) -> [f64; 3] {
+    let length = math::sqrt(vector[0] * vector[0] + vector[1] * vector[1] + vector[2] * vector[2]);
+    assert!(length > 0.0, "direction can't be zero");
+    [vector[0] / length, vector[1] / length, vector[2] / length]
+}
+
+impl<H: PointHash> OpenSimplexGenerator<H> {
+    pub fn eval_sphere(&self, sphere: &Sphere, point: SpherePoint) -> f64 {
+        let [x, y, z] = sphere.noise_point(point.direction());
+        self.eval_3d(x, y, z)
+    }
+
+    // Animated through the fourth dimension, `time` being in noise units.
+    pub fn eval_sphere_animated(&self, sphere: &Sphere, point: SpherePoint, time: f64) -> f64 {
+        let [x, y, z] = sphere.noise_point(point.direction());
+        self.eval_4d(x, y, z, time)
+    }
+}
@@ -1,24 +1,106 @@
+use core::ops::{Add, AddAssign, Div};
+
+use super::float::Float;
+use super::hash::LatticeHash;
 use super::vector::VecType;
 use super::NoiseEvaluator;
-use super::PermTable;
 
-pub fn contribute<NoiseEvaluatorType: NoiseEvaluator<Vec>, Vec: VecType<f64>>(
+// Anything the lattice points can contribute: plain noise values or values with their derivatives.
+pub trait Contribution<F>:
+    Copy + Default + Add<Output = Self> + AddAssign + Div<F, Output = Self>
+{
+}
+impl<F, T: Copy + Default + Add<Output = T> + AddAssign + Div<F, Output = T>> Contribution<F>
+    for T
+{
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct ValueWithGradient<F, Vec> {
+    pub value: F,
+    pub gradient: Vec,
+}
+
+impl<F: Float, Vec: Add<Output = Vec>> Add for ValueWithGradient<F, Vec> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value + rhs.value,
+            gradient: self.gradient + rhs.gradient,
+        }
+    }
+}
+
+impl<F: Float, Vec: Add<Output = Vec> + Copy> AddAssign for ValueWithGradient<F, Vec> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F: Float, Vec: Div<F, Output = Vec>> Div<F> for ValueWithGradient<F, Vec> {
+    type Output = Self;
+
+    fn div(self, rhs: F) -> Self::Output {
+        Self {
+            value: self.value / rhs,
+            gradient: self.gradient / rhs,
+        }
+    }
+}
+
+// Attenuated extrapolation of the gradient at the lattice point `grid + delta`, `origin` being
+// the evaluated point relative to the squashed `grid`.
+pub(crate) fn contribute<
+    NoiseEvaluatorType: NoiseEvaluator<Vec, F>,
+    F: Float,
+    Vec: VecType<F>,
+    H: LatticeHash<Vec>,
+>(
+    delta: Vec,
+    origin: Vec,
+    grid: Vec,
+    perm: &H,
+) -> F {
+    let shifted: Vec = origin - delta - NoiseEvaluatorType::squish_point() * delta.sum();
+    let weight = NoiseEvaluatorType::kernel(shifted);
+    if weight > F::from_f64(0.0) {
+        return weight * NoiseEvaluatorType::extrapolate(grid + delta, shifted, perm);
+    }
+
+    F::from_f64(0.0)
+}
+
+pub(crate) fn contribute_with_gradient<
+    NoiseEvaluatorType: NoiseEvaluator<Vec, F>,
+    F: Float,
+    Vec: VecType<F> + Default,
+    H: LatticeHash<Vec>,
+>(
     delta: Vec,
     origin: Vec,
     grid: Vec,
-    perm: &PermTable,
-) -> f64 {
-    let shifted: Vec = origin - delta - NoiseEvaluatorType::SQUISH_POINT * delta.sum();
-    let attn: f64 = 2.0 - shifted.get_attenuation_factor();
-    if attn > 0.0 {
-        return attn.powi(4) * NoiseEvaluatorType::extrapolate(grid + delta, shifted, perm);
+    perm: &H,
+) -> ValueWithGradient<F, Vec> {
+    let shifted: Vec = origin - delta - NoiseEvaluatorType::squish_point() * delta.sum();
+    let weight = NoiseEvaluatorType::kernel(shifted);
+    if weight > F::from_f64(0.0) {
+        let grad = NoiseEvaluatorType::get_grad(grid + delta, perm);
+        let extrapolation = grad.dot(&shifted);
+
+        // d(kernel * <grad, shifted>) = kernel * grad + <grad, shifted> * d(kernel),
+        // since shifted moves together with the input point.
+        return ValueWithGradient {
+            value: weight * extrapolation,
+            gradient: grad * weight + NoiseEvaluatorType::kernel_gradient(shifted) * extrapolation,
+        };
     }
 
-    0.0
+    ValueWithGradient::default()
 }
 
-pub fn floor(x: f64) -> i64 {
-    x.floor() as i64
+pub fn floor<F: Float>(x: F) -> i64 {
+    x.floor().to_i64()
 }
 
 pub fn to_f64(x: i64) -> f64 {
@@ -1,12 +1,15 @@
+pub mod vec1;
 pub mod vec2;
 pub mod vec3;
 pub mod vec4;
+pub mod vecn;
 
-use std::ops::{Add, Mul, Sub};
+use core::ops::{Add, Mul, Sub};
 
 pub trait VecMethods<T> {
     fn sum(&self) -> T;
     fn get_attenuation_factor(&self) -> T;
+    fn dot(&self, other: &Self) -> T;
 }
 
 pub trait VecType<T>:
@@ -15,7 +18,7 @@ pub trait VecType<T>:
     + Sub<Self, Output = Self>
     + Add<Self, Output = Self>
     + Mul<T, Output = Self>
-    + std::marker::Sized
+    + core::marker::Sized
 {
 }
 impl<
@@ -28,12 +31,3 @@ impl<
     > VecType<T> for X
 {
 }
-
-trait VecArgumentType:
-    Sub<Self, Output = Self> + Add<Self, Output = Self> + Mul<Self, Output = Self> + Copy
-{
-}
-impl<T: Sub<Self, Output = Self> + Add<Self, Output = Self> + Mul<Self, Output = Self> + Copy>
-    VecArgumentType for T
-{
-}
@@ -0,0 +1,85 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use super::VecMethods;
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Vec1<T> {
+    pub x: T,
+}
+
+impl<T> Vec1<T> {
+    pub const fn new(x: T) -> Self {
+        Self { x }
+    }
+}
+
+impl<T> VecMethods<T> for Vec1<T>
+where
+    T: Add<Output = T>,
+    T: Mul<Output = T>,
+    T: Sub<Output = T>,
+    T: Copy,
+{
+    fn sum(&self) -> T {
+        self.x
+    }
+    fn get_attenuation_factor(&self) -> T {
+        self.x * self.x
+    }
+
+    fn dot(&self, other: &Self) -> T {
+        self.x * other.x
+    }
+}
+
+impl<T> Vec1<T>
+where
+    T: Copy,
+{
+    pub fn map<Y>(&self, f: impl Fn(T) -> Y) -> Vec1<Y> {
+        Vec1 { x: f(self.x) }
+    }
+}
+
+impl<T> Sub<Vec1<T>> for Vec1<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Vec1<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec1 { x: self.x - rhs.x }
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vec1<T>> for Vec1<T> {
+    type Output = Vec1<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vec1 { x: self.x + rhs.x }
+    }
+}
+
+impl<T> Mul<T> for Vec1<T>
+where
+    T: Mul<Output = T>,
+    T: Copy,
+{
+    type Output = Vec1<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Vec1 { x: self.x * rhs }
+    }
+}
+
+impl<T> Div<T> for Vec1<T>
+where
+    T: Div<Output = T>,
+    T: Copy,
+{
+    type Output = Vec1<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vec1 { x: self.x / rhs }
+    }
+}
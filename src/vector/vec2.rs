@@ -1,8 +1,8 @@
-use std::ops::{Add, Mul, Sub};
+use core::ops::{Add, Div, Mul, Sub};
 
 use super::VecMethods;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default)]
 pub struct Vec2<T> {
     pub x: T,
     pub y: T,
@@ -27,6 +27,10 @@ where
     fn get_attenuation_factor(&self) -> T {
         (self.x * self.x) + (self.y * self.y)
     }
+
+    fn dot(&self, other: &Self) -> T {
+        (self.x * other.x) + (self.y * other.y)
+    }
 }
 
 impl<T> Vec2<T>
@@ -80,3 +84,18 @@ where
         }
     }
 }
+
+impl<T> Div<T> for Vec2<T>
+where
+    T: Div<Output = T>,
+    T: Copy,
+{
+    type Output = Vec2<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vec2 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use std::ops::{Add, Mul, Sub};
+use core::ops::{Add, Div, Mul, Sub};
 
 use super::VecMethods;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default)]
 pub struct Vec3<T> {
     pub x: T,
     pub y: T,
@@ -29,6 +29,10 @@ where
     fn get_attenuation_factor(&self) -> T {
         (self.x * self.x) + (self.y * self.y) + (self.z * self.z)
     }
+
+    fn dot(&self, other: &Self) -> T {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
+    }
 }
 
 impl<T: Copy> Vec3<T> {
@@ -83,3 +87,19 @@ where
         }
     }
 }
+
+impl<T> Div<T> for Vec3<T>
+where
+    T: Div<Output = T>,
+    T: Copy,
+{
+    type Output = Vec3<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vec3 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+        }
+    }
+}
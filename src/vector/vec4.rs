@@ -1,8 +1,8 @@
-use std::ops::{Add, Mul, Sub};
+use core::ops::{Add, Div, Mul, Sub};
 
 use super::VecMethods;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default)]
 pub struct Vec4<T> {
     pub x: T,
     pub y: T,
@@ -30,6 +30,10 @@ where
     fn get_attenuation_factor(&self) -> T {
         (self.x * self.x) + (self.y * self.y) + (self.z * self.z) + (self.w * self.w)
     }
+
+    fn dot(&self, other: &Self) -> T {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z) + (self.w * other.w)
+    }
 }
 
 impl<T: Copy> Vec4<T> {
@@ -88,3 +92,20 @@ where
         }
     }
 }
+
+impl<T> Div<T> for Vec4<T>
+where
+    T: Div<Output = T>,
+    T: Copy,
+{
+    type Output = Vec4<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vec4 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+            w: self.w / rhs,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use super::VecMethods;
+
+// Vector of any dimension, for the N-dimensional noise.
+#[derive(Copy, Clone, Debug)]
+pub struct VecN<T, const N: usize> {
+    pub coords: [T; N],
+}
+
+impl<T, const N: usize> VecN<T, N> {
+    pub const fn new(coords: [T; N]) -> Self {
+        Self { coords }
+    }
+}
+
+impl<T: Copy + Default, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self {
+        Self::new([T::default(); N])
+    }
+}
+
+impl<T, const N: usize> VecMethods<T> for VecN<T, N>
+where
+    T: Add<Output = T>,
+    T: Mul<Output = T>,
+    T: Sub<Output = T>,
+    T: Copy,
+{
+    fn sum(&self) -> T {
+        self.coords[1..]
+            .iter()
+            .fold(self.coords[0], |sum, &coord| sum + coord)
+    }
+    fn get_attenuation_factor(&self) -> T {
+        self.dot(self)
+    }
+
+    fn dot(&self, other: &Self) -> T {
+        (1..N).fold(self.coords[0] * other.coords[0], |sum, i| {
+            sum + self.coords[i] * other.coords[i]
+        })
+    }
+}
+
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Copy,
+{
+    pub fn map<Y>(&self, f: impl Fn(T) -> Y) -> VecN<Y, N> {
+        VecN {
+            coords: self.coords.map(f),
+        }
+    }
+
+    fn zip(self, rhs: Self, f: impl Fn(T, T) -> T) -> Self {
+        let mut coords = self.coords;
+        for (coord, &other) in coords.iter_mut().zip(rhs.coords.iter()) {
+            *coord = f(*coord, other);
+        }
+        VecN { coords }
+    }
+}
+
+impl<T, const N: usize> Sub<VecN<T, N>> for VecN<T, N>
+where
+    T: Sub<Output = T>,
+    T: Copy,
+{
+    type Output = VecN<T, N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, |a, b| a - b)
+    }
+}
+
+impl<T: Add<Output = T> + Copy, const N: usize> Add<VecN<T, N>> for VecN<T, N> {
+    type Output = VecN<T, N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, |a, b| a + b)
+    }
+}
+
+impl<T, const N: usize> Mul<T> for VecN<T, N>
+where
+    T: Mul<Output = T>,
+    T: Copy,
+{
+    type Output = VecN<T, N>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|coord| coord * rhs)
+    }
+}
+
+impl<T, const N: usize> Div<T> for VecN<T, N>
+where
+    T: Div<Output = T>,
+    T: Copy,
+{
+    type Output = VecN<T, N>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|coord| coord / rhs)
+    }
+}
@@ -0,0 +1,94 @@
+use opensimplex_noise_rs::{HashedOpenSimplexNoise, NoiseVersion, OpenSimplexNoise};
+
+const SEED: i64 = 883_279_212_983_182_319;
+const POINTS: usize = 4099;
+
+// Scattered coordinates in [-64, 64), some of them right on lattice cell boundaries
+fn coordinates(salt: u64) -> Vec<f64> {
+    let mut state = salt;
+    (0..POINTS)
+        .map(|i| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let value = (state >> 11) as f64 / (1u64 << 53) as f64 * 128.0 - 64.0;
+            if i % 7 == 0 {
+                value.round()
+            } else {
+                value
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn batch_matches_single_point_evaluation() {
+    let (xs, ys, zs, ws) = (
+        coordinates(1),
+        coordinates(2),
+        coordinates(3),
+        coordinates(4),
+    );
+
+    for &version in &[NoiseVersion::V0_3, NoiseVersion::V0_4] {
+        let noise = OpenSimplexNoise::with_version(Some(SEED), version);
+        let mut out = vec![0.0; POINTS];
+
+        noise.eval_2d_batch(&xs, &ys, &mut out);
+        for i in 0..POINTS {
+            assert_eq!(out[i].to_bits(), noise.eval_2d(xs[i], ys[i]).to_bits());
+        }
+
+        noise.eval_3d_batch(&xs, &ys, &zs, &mut out);
+        for i in 0..POINTS {
+            assert_eq!(
+                out[i].to_bits(),
+                noise.eval_3d(xs[i], ys[i], zs[i]).to_bits()
+            );
+        }
+
+        noise.eval_4d_batch(&xs, &ys, &zs, &ws, &mut out);
+        for i in 0..POINTS {
+            assert_eq!(
+                out[i].to_bits(),
+                noise.eval_4d(xs[i], ys[i], zs[i], ws[i]).to_bits()
+            );
+        }
+    }
+}
+
+#[test]
+fn hashed_batch_matches_single_point_evaluation() {
+    let (xs, ys, zs, ws) = (
+        coordinates(5),
+        coordinates(6),
+        coordinates(7),
+        coordinates(8),
+    );
+
+    for &version in &[NoiseVersion::V0_3, NoiseVersion::V0_4] {
+        let noise = HashedOpenSimplexNoise::with_version(Some(SEED), version);
+        let mut out = vec![0.0; POINTS];
+
+        noise.eval_2d_batch(&xs, &ys, &mut out);
+        for i in 0..POINTS {
+            assert_eq!(out[i].to_bits(), noise.eval_2d(xs[i], ys[i]).to_bits());
+        }
+
+        noise.eval_3d_batch(&xs, &ys, &zs, &mut out);
+        for i in 0..POINTS {
+            assert_eq!(
+                out[i].to_bits(),
+                noise.eval_3d(xs[i], ys[i], zs[i]).to_bits()
+            );
+        }
+
+        noise.eval_4d_batch(&xs, &ys, &zs, &ws, &mut out);
+        for i in 0..POINTS {
+            assert_eq!(
+                out[i].to_bits(),
+                noise.eval_4d(xs[i], ys[i], zs[i], ws[i]).to_bits()
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use opensimplex_noise_rs::{HashPeriod, HashedOpenSimplexNoise, NoiseVersion, OpenSimplexNoise};
+
+static TERRAIN: OpenSimplexNoise = OpenSimplexNoise::from_seed_const(42);
+static LEGACY: OpenSimplexNoise = OpenSimplexNoise::with_version(Some(-5), NoiseVersion::V0_3)
+    .with_hash_period(HashPeriod::Cells2048);
+static ENTITY: HashedOpenSimplexNoise = HashedOpenSimplexNoise::new(Some(42));
+
+#[test]
+fn statics_match_runtime_construction() {
+    let terrain = OpenSimplexNoise::new(Some(42));
+    let legacy = OpenSimplexNoise::with_version(Some(-5), NoiseVersion::V0_3)
+        .with_hash_period(HashPeriod::Cells2048);
+
+    for i in 0..1000 {
+        let (x, y, z) = (i as f64 * 0.173, i as f64 * -0.091, i as f64 * 0.057);
+        assert_eq!(TERRAIN.eval_3d(x, y, z), terrain.eval_3d(x, y, z));
+        assert_eq!(LEGACY.eval_3d(x, y, z), legacy.eval_3d(x, y, z));
+        assert_eq!(
+            ENTITY.eval_2d(x, y),
+            HashedOpenSimplexNoise::new(Some(42)).eval_2d(x, y)
+        );
+    }
+}
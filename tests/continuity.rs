@@ -0,0 +1,123 @@
+use opensimplex_noise_rs::{NoiseVersion, OpenSimplexNoise};
+
+const SEED: i64 = 42;
+
+const SQUISH_2D: f64 = 0.366_025_403_784_439;
+const SQUISH_3D: f64 = 1.0 / 3.0;
+const SQUISH_4D: f64 = 0.309_016_994_374_947;
+
+fn random(state: &mut u64) -> f64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    (*state >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// Points whose stretched coordinates share their fractional part on two axes. The choice of
+// lattice points changes there, so any point that drops out with some weight left shows up
+// as a jump.
+fn tie_points<const N: usize>(squish: f64, count: usize) -> impl Iterator<Item = [f64; N]> {
+    let mut state = N as u64;
+    (0..count).map(move |_| {
+        let (mut cell, mut ins) = ([0.0; N], [0.0; N]);
+        for (cell, ins) in cell.iter_mut().zip(ins.iter_mut()) {
+            *cell = (random(&mut state) * 32.0 - 16.0).floor();
+            *ins = random(&mut state);
+        }
+        let a = (random(&mut state) * N as f64) as usize;
+        let b = (a + 1 + (random(&mut state) * (N - 1) as f64) as usize) % N;
+        ins[b] = ins[a];
+
+        let mut point = [0.0; N];
+        for ((coord, cell), ins) in point.iter_mut().zip(cell.iter()).zip(ins.iter()) {
+            *coord = cell + ins;
+        }
+        let sum: f64 = point.iter().sum();
+        point.map(|coord| coord + squish * sum)
+    })
+}
+
+// Both sides of `point`, a hair apart along every axis
+fn assert_no_jump_at<const N: usize>(eval: impl Fn([f64; N]) -> f64, point: [f64; N]) {
+    for axis in 0..N {
+        let (mut ahead, mut behind) = (point, point);
+        ahead[axis] += 1e-9;
+        behind[axis] -= 1e-9;
+        let jump = eval(ahead) - eval(behind);
+        assert!(jump.abs() < 1e-7, "jump of {} at {:?}", jump, point);
+    }
+}
+
+// Walks lines in random directions with tiny steps, a jump shows up as a step much larger than
+// the slope of the noise allows.
+fn assert_continuous<const N: usize>(eval: impl Fn([f64; N]) -> f64) {
+    let step = 1e-5;
+    let mut state = 7;
+    for _ in 0..10 {
+        let (mut start, mut direction) = ([0.0; N], [0.0; N]);
+        for (coord, direction) in start.iter_mut().zip(direction.iter_mut()) {
+            *coord = random(&mut state) * 128.0 - 64.0;
+            *direction = random(&mut state) * 2.0 - 1.0;
+        }
+        let length = direction
+            .iter()
+            .map(|coord| coord * coord)
+            .sum::<f64>()
+            .sqrt();
+
+        let point_at = |i: u32| {
+            let mut point = start;
+            for (coord, direction) in point.iter_mut().zip(direction.iter()) {
+                *coord += direction / length * step * f64::from(i);
+            }
+            point
+        };
+        let mut previous = eval(point_at(0));
+        for i in 1..50_000 {
+            let value = eval(point_at(i));
+            assert!(
+                (value - previous).abs() < 1e-4,
+                "jump of {} at {:?}",
+                value - previous,
+                point_at(i)
+            );
+            previous = value;
+        }
+    }
+}
+
+fn noise() -> OpenSimplexNoise {
+    OpenSimplexNoise::with_version(Some(SEED), NoiseVersion::V0_4)
+}
+
+#[test]
+fn noise_is_continuous() {
+    let noise = noise();
+    assert_continuous(|[x, y]| noise.eval_2d(x, y));
+    assert_continuous(|[x, y, z]| noise.eval_3d(x, y, z));
+    assert_continuous(|[x, y, z, w]| noise.eval_4d(x, y, z, w));
+}
+
+#[test]
+fn noise_has_no_jumps_between_regions() {
+    let noise = noise();
+    for point in tie_points(SQUISH_2D, 20_000) {
+        assert_no_jump_at(|[x, y]| noise.eval_2d(x, y), point);
+    }
+    for point in tie_points(SQUISH_3D, 20_000) {
+        assert_no_jump_at(|[x, y, z]| noise.eval_3d(x, y, z), point);
+    }
+    for point in tie_points(SQUISH_4D, 20_000) {
+        assert_no_jump_at(|[x, y, z, w]| noise.eval_4d(x, y, z, w), point);
+    }
+}
+
+// Where 0.3 releases swap (-1, 1, 0, 1) for (-1, 0, 1, 1)
+#[test]
+fn noise_has_no_jump_where_0_3_did() {
+    let noise = noise();
+    assert_no_jump_at(
+        |[x, y, z, w]| noise.eval_4d(x, y, z, w),
+        [2.934, 4.286, 3.286, 3.487],
+    );
+}
@@ -0,0 +1,82 @@
+use opensimplex_noise_rs::{HashedOpenSimplexNoise, NoiseVersion, OpenSimplexNoise};
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+// Scattered points in [-64, 64)^3
+fn points() -> impl Iterator<Item = [f64; 3]> {
+    let mut state: u64 = 3;
+    (0..5000).map(move |_| {
+        let mut point = [0.0; 3];
+        for coord in point.iter_mut() {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            *coord = (state >> 11) as f64 / (1u64 << 53) as f64 * 128.0 - 64.0;
+        }
+        point
+    })
+}
+
+// Central difference of `field` component `axis` along `axis`, summed over the axes
+fn divergence<const N: usize>(field: impl Fn([f64; N]) -> [f64; N], point: [f64; N]) -> f64 {
+    let step = 1e-5;
+    (0..N)
+        .map(|axis| {
+            let (mut ahead, mut behind) = (point, point);
+            ahead[axis] += step;
+            behind[axis] -= step;
+            (field(ahead)[axis] - field(behind)[axis]) / (2.0 * step)
+        })
+        .sum()
+}
+
+// The differences are taken with `V0_4`, the jumps of `V0_3` would show up as divergence
+#[test]
+fn curl_2d_is_divergence_free() {
+    let noise = OpenSimplexNoise::with_version(Some(SEED), NoiseVersion::V0_4);
+    for [x, y, _] in points() {
+        let divergence = divergence(|[x, y]| noise.curl_2d(x, y), [x, y]);
+        assert!(divergence.abs() < 1e-4, "{}", divergence);
+    }
+}
+
+#[test]
+fn curl_3d_is_divergence_free() {
+    let noise = OpenSimplexNoise::with_version(Some(SEED), NoiseVersion::V0_4);
+    for point in points() {
+        let divergence = divergence(|[x, y, z]| noise.curl_3d(x, y, z), point);
+        assert!(divergence.abs() < 1e-4, "{}", divergence);
+    }
+}
+
+#[test]
+fn curl_3d_uses_the_default_offsets() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    for [x, y, z] in points() {
+        assert_eq!(
+            noise.curl_3d(x, y, z),
+            noise.curl_3d_with_offsets(x, y, z, [0.0, 113.5, 271.25])
+        );
+    }
+}
+
+#[test]
+fn curl_3d_offsets_change_the_flow() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    assert!(points().any(|[x, y, z]| {
+        noise.curl_3d(x, y, z) != noise.curl_3d_with_offsets(x, y, z, [5.0, 50.0, 500.0])
+    }));
+}
+
+#[test]
+fn hashed_curl_is_divergence_free() {
+    let noise = HashedOpenSimplexNoise::with_version(Some(SEED), NoiseVersion::V0_4);
+    for point in points().take(1000) {
+        let divergence_3d = divergence(|[x, y, z]| noise.curl_3d(x, y, z), point);
+        assert!(divergence_3d.abs() < 1e-4, "{}", divergence_3d);
+
+        let [x, y, _] = point;
+        let divergence_2d = divergence(|[x, y]| noise.curl_2d(x, y), [x, y]);
+        assert!(divergence_2d.abs() < 1e-4, "{}", divergence_2d);
+    }
+}
@@ -0,0 +1,144 @@
+use opensimplex_noise_rs::{
+    LatticeHash, NoiseEvaluator, NoiseVersion, OpenSimplexNoise, OpenSimplexNoise3D, Vec3,
+    VecMethods,
+};
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+// The built-in 3D noise with every gradient twice as long
+struct Doubled;
+
+impl NoiseEvaluator<Vec3<f64>> for Doubled {
+    fn stretch_point() -> Vec3<f64> {
+        <OpenSimplexNoise3D as NoiseEvaluator<Vec3<f64>>>::stretch_point()
+    }
+
+    fn squish_point() -> Vec3<f64> {
+        <OpenSimplexNoise3D as NoiseEvaluator<Vec3<f64>>>::squish_point()
+    }
+
+    fn eval<H: LatticeHash<Vec3<f64>>>(point: Vec3<f64>, perm: &H, version: NoiseVersion) -> f64 {
+        OpenSimplexNoise3D::evaluate(point, perm, version, Self::contribute)
+    }
+
+    fn eval_with_gradient<H: LatticeHash<Vec3<f64>>>(
+        point: Vec3<f64>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> (f64, Vec3<f64>) {
+        let result =
+            OpenSimplexNoise3D::evaluate(point, perm, version, Self::contribute_with_gradient);
+        (result.value, result.gradient)
+    }
+
+    fn extrapolate<H: LatticeHash<Vec3<f64>>>(grid: Vec3<f64>, delta: Vec3<f64>, perm: &H) -> f64 {
+        Self::get_grad(grid, perm).dot(&delta)
+    }
+
+    fn get_grad<H: LatticeHash<Vec3<f64>>>(grid: Vec3<f64>, perm: &H) -> Vec3<f64> {
+        OpenSimplexNoise3D::get_grad(grid, perm) * 2.0
+    }
+}
+
+// The built-in 3D noise with the default kernel three times as strong
+struct Tripled;
+
+impl NoiseEvaluator<Vec3<f64>> for Tripled {
+    fn stretch_point() -> Vec3<f64> {
+        <OpenSimplexNoise3D as NoiseEvaluator<Vec3<f64>>>::stretch_point()
+    }
+
+    fn squish_point() -> Vec3<f64> {
+        <OpenSimplexNoise3D as NoiseEvaluator<Vec3<f64>>>::squish_point()
+    }
+
+    fn eval<H: LatticeHash<Vec3<f64>>>(point: Vec3<f64>, perm: &H, version: NoiseVersion) -> f64 {
+        OpenSimplexNoise3D::evaluate(point, perm, version, Self::contribute)
+    }
+
+    fn eval_with_gradient<H: LatticeHash<Vec3<f64>>>(
+        point: Vec3<f64>,
+        perm: &H,
+        version: NoiseVersion,
+    ) -> (f64, Vec3<f64>) {
+        let result =
+            OpenSimplexNoise3D::evaluate(point, perm, version, Self::contribute_with_gradient);
+        (result.value, result.gradient)
+    }
+
+    fn extrapolate<H: LatticeHash<Vec3<f64>>>(grid: Vec3<f64>, delta: Vec3<f64>, perm: &H) -> f64 {
+        Self::get_grad(grid, perm).dot(&delta)
+    }
+
+    fn get_grad<H: LatticeHash<Vec3<f64>>>(grid: Vec3<f64>, perm: &H) -> Vec3<f64> {
+        OpenSimplexNoise3D::get_grad(grid, perm)
+    }
+
+    fn kernel(delta: Vec3<f64>) -> f64 {
+        3.0 * <OpenSimplexNoise3D as NoiseEvaluator<Vec3<f64>>>::kernel(delta)
+    }
+
+    fn kernel_gradient(delta: Vec3<f64>) -> Vec3<f64> {
+        <OpenSimplexNoise3D as NoiseEvaluator<Vec3<f64>>>::kernel_gradient(delta) * 3.0
+    }
+}
+
+#[test]
+fn custom_kernels_plug_into_the_lattice_walk() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let version = noise.version();
+
+    for i in 0..1000 {
+        let (x, y, z) = (i as f64 * 0.173, i as f64 * -0.091, i as f64 * 0.057);
+        let (value, gradient) =
+            Tripled::eval_with_gradient(Vec3::new(x, y, z), &noise.lattice_hash(), version);
+        let (expected_value, expected_gradient) = noise.eval_3d_with_gradient(x, y, z);
+        assert!((value - 3.0 * expected_value).abs() < 1e-12);
+        assert!((gradient.x - 3.0 * expected_gradient[0]).abs() < 1e-12);
+        assert!((gradient.y - 3.0 * expected_gradient[1]).abs() < 1e-12);
+        assert!((gradient.z - 3.0 * expected_gradient[2]).abs() < 1e-12);
+    }
+}
+
+// Same gradient everywhere
+struct Constant;
+
+impl LatticeHash<Vec3<f64>> for Constant {
+    fn hash(&self, _grid: Vec3<f64>) -> i64 {
+        0
+    }
+}
+
+#[test]
+fn custom_gradients_plug_into_the_lattice_walk() {
+    for &version in &[NoiseVersion::V0_3, NoiseVersion::V0_4] {
+        let noise = OpenSimplexNoise::with_version(Some(SEED), version);
+
+        for i in 0..1000 {
+            let (x, y, z) = (i as f64 * 0.173, i as f64 * -0.091, i as f64 * 0.057);
+            let value = Doubled::eval(Vec3::new(x, y, z), &noise.lattice_hash(), version);
+            assert_eq!(value, 2.0 * noise.eval_3d(x, y, z));
+
+            let (value, gradient) =
+                Doubled::eval_with_gradient(Vec3::new(x, y, z), &noise.lattice_hash(), version);
+            let (expected_value, expected_gradient) = noise.eval_3d_with_gradient(x, y, z);
+            assert_eq!(value, 2.0 * expected_value);
+            assert_eq!(gradient.x, 2.0 * expected_gradient[0]);
+            assert_eq!(gradient.y, 2.0 * expected_gradient[1]);
+            assert_eq!(gradient.z, 2.0 * expected_gradient[2]);
+        }
+    }
+}
+
+// Moving by (2, 2, 2) moves by (1, 1, 1) on the lattice, which a constant hash can't tell apart
+#[test]
+fn custom_hash_replaces_the_permutation_table() {
+    for i in 0..1000 {
+        let point = Vec3::new(i as f64 * 0.173, i as f64 * -0.091, i as f64 * 0.057);
+        let shifted = point + Vec3::new(2.0, 2.0, 2.0);
+
+        let value = OpenSimplexNoise3D::eval(point, &Constant, NoiseVersion::V0_4);
+        let shifted_value = OpenSimplexNoise3D::eval(shifted, &Constant, NoiseVersion::V0_4);
+        assert!((value - shifted_value).abs() < 1e-9);
+    }
+}
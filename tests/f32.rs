@@ -0,0 +1,64 @@
+use opensimplex_noise_rs::OpenSimplexNoise;
+
+const SEED: i64 = 883_279_212_983_182_319;
+const POINTS: usize = 4099;
+const TOLERANCE: f64 = 1e-3;
+
+// Scattered coordinates in [-64, 64), some of them right on lattice cell boundaries
+fn coordinates(salt: u64) -> Vec<f32> {
+    let mut state = salt;
+    (0..POINTS)
+        .map(|i| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let value = (state >> 24) as f32 / (1u64 << 40) as f32 * 128.0 - 64.0;
+            if i % 7 == 0 {
+                value.round()
+            } else {
+                value
+            }
+        })
+        .collect()
+}
+
+fn assert_close(single: f32, double: f64) {
+    assert!(
+        (single as f64 - double).abs() < TOLERANCE,
+        "f32 output {} is too far from f64 output {}",
+        single,
+        double
+    );
+}
+
+#[test]
+fn f32_follows_f64_evaluation() {
+    let (xs, ys, zs, ws) = (
+        coordinates(1),
+        coordinates(2),
+        coordinates(3),
+        coordinates(4),
+    );
+    let noise = OpenSimplexNoise::new(Some(SEED));
+
+    for i in 0..POINTS {
+        let (x, y, z, w) = (xs[i] as f64, ys[i] as f64, zs[i] as f64, ws[i] as f64);
+
+        assert_close(noise.eval_2d_f32(xs[i], ys[i]), noise.eval_2d(x, y));
+        assert_close(
+            noise.eval_3d_f32(xs[i], ys[i], zs[i]),
+            noise.eval_3d(x, y, z),
+        );
+        assert_close(
+            noise.eval_4d_f32(xs[i], ys[i], zs[i], ws[i]),
+            noise.eval_4d(x, y, z, w),
+        );
+
+        let (value, gradient) = noise.eval_3d_with_gradient_f32(xs[i], ys[i], zs[i]);
+        let (expected_value, expected_gradient) = noise.eval_3d_with_gradient(x, y, z);
+        assert_close(value, expected_value);
+        for axis in 0..3 {
+            assert_close(gradient[axis], expected_gradient[axis]);
+        }
+    }
+}
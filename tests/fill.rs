@@ -0,0 +1,131 @@
+use opensimplex_noise_rs::{HashedOpenSimplexNoise, NoiseVersion, OpenSimplexNoise, VolumeLayout};
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+// Origins and steps: off the lattice, on lattice-aligned coordinates, more than the walked
+// cells per sample, and walking backwards
+const GRIDS: [([f64; 2], f64); 4] = [
+    ([-3.2, 7.1], 0.07),
+    ([0.0, 0.0], 0.25),
+    ([12.7, -40.3], 5.3),
+    ([4.0, 2.5], -0.13),
+];
+
+#[test]
+fn fill_2d_matches_eval_2d() {
+    let (width, height) = (97, 64);
+    for &version in &[NoiseVersion::V0_3, NoiseVersion::V0_4] {
+        let noise = OpenSimplexNoise::with_version(Some(SEED), version);
+        for &(origin, step) in &GRIDS {
+            let mut values = vec![0.0; width * height];
+            let mut values_f32 = vec![0.0; width * height];
+            noise.fill_2d(&mut values, width, height, origin, step);
+            noise.fill_2d_f32(&mut values_f32, width, height, origin, step);
+
+            for j in 0..height {
+                for i in 0..width {
+                    let expected =
+                        noise.eval_2d(origin[0] + i as f64 * step, origin[1] + j as f64 * step);
+                    assert_eq!(values[j * width + i], expected);
+                    assert_eq!(values_f32[j * width + i], expected as f32);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn fill_3d_matches_eval_3d() {
+    let size = [17, 9, 24];
+    let origin = [1.5, -0.4, 12.0];
+    for &version in &[NoiseVersion::V0_3, NoiseVersion::V0_4] {
+        let noise = OpenSimplexNoise::with_version(Some(SEED), version);
+        for &step in &[0.11, 0.5, 5.3, -0.13] {
+            let eval = |x: usize, y: usize, z: usize| {
+                noise.eval_3d(
+                    origin[0] + x as f64 * step,
+                    origin[1] + y as f64 * step,
+                    origin[2] + z as f64 * step,
+                )
+            };
+
+            let mut x_major = vec![0.0; 17 * 9 * 24];
+            let mut z_major = vec![0.0; 17 * 9 * 24];
+            noise.fill_3d(&mut x_major, size, origin, step, VolumeLayout::XMajor);
+            noise.fill_3d(&mut z_major, size, origin, step, VolumeLayout::ZMajor);
+            for x in 0..17 {
+                for y in 0..9 {
+                    for z in 0..24 {
+                        assert_eq!(x_major[(x * 9 + y) * 24 + z], eval(x, y, z));
+                        assert_eq!(z_major[(z * 9 + y) * 17 + x], eval(x, y, z));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// A chunk written into a larger volume, with Y varying fastest
+#[test]
+fn fill_3d_strided_matches_eval_3d() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let size = [5, 7, 3];
+    let strides = [8 * 4, 1, 8];
+    let origin = [-2.0, 3.3, 0.7];
+
+    let mut volume = vec![f64::NAN; 6 * 8 * 4];
+    noise.fill_3d_strided(&mut volume, size, origin, 0.23, strides);
+    for (index, value) in volume.iter().enumerate() {
+        let (x, y, z) = (
+            index / strides[0],
+            index % strides[2],
+            index % strides[0] / strides[2],
+        );
+        if x < 5 && y < 7 && z < 3 {
+            let expected = noise.eval_3d(
+                origin[0] + x as f64 * 0.23,
+                origin[1] + y as f64 * 0.23,
+                origin[2] + z as f64 * 0.23,
+            );
+            assert_eq!(*value, expected);
+        } else {
+            assert!(value.is_nan());
+        }
+    }
+}
+
+#[test]
+fn hashed_fills_match_eval() {
+    let noise = HashedOpenSimplexNoise::new(Some(SEED));
+    let (width, height) = (37, 21);
+    let mut image = vec![0.0; width * height];
+    noise.fill_2d(&mut image, width, height, [-3.2, 7.1], 0.07);
+    for j in 0..height {
+        for i in 0..width {
+            let expected = noise.eval_2d(-3.2 + i as f64 * 0.07, 7.1 + j as f64 * 0.07);
+            assert_eq!(image[j * width + i], expected);
+        }
+    }
+
+    let size = [7, 5, 9];
+    let mut volume = vec![0.0; 7 * 5 * 9];
+    noise.fill_3d(
+        &mut volume,
+        size,
+        [1.5, -0.4, 12.0],
+        0.11,
+        VolumeLayout::XMajor,
+    );
+    for x in 0..7 {
+        for y in 0..5 {
+            for z in 0..9 {
+                let expected = noise.eval_3d(
+                    1.5 + x as f64 * 0.11,
+                    -0.4 + y as f64 * 0.11,
+                    12.0 + z as f64 * 0.11,
+                );
+                assert_eq!(volume[(x * 5 + y) * 9 + z], expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use opensimplex_noise_rs::{Billow, Fbm, HashedOpenSimplexNoise, OpenSimplexNoise, RidgedMulti};
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+fn points() -> impl Iterator<Item = (f64, f64, f64)> {
+    (0..2000).map(|i| {
+        let t = i as f64;
+        (t * 0.173 - 80.0, t * -0.091 + 30.0, t * 0.057 - 9.0)
+    })
+}
+
+fn noise() -> OpenSimplexNoise {
+    OpenSimplexNoise::new(Some(SEED))
+}
+
+#[test]
+fn fbm_with_one_octave_is_the_noise_at_its_frequency() {
+    let fbm = Fbm::new(noise()).with_octaves(1).with_frequency(2.5);
+    let noise = noise();
+
+    for (x, y, z) in points() {
+        assert_eq!(fbm.eval_2d(x, y), noise.eval_2d(x * 2.5, y * 2.5));
+        assert_eq!(
+            fbm.eval_3d(x, y, z),
+            noise.eval_3d(x * 2.5, y * 2.5, z * 2.5)
+        );
+    }
+}
+
+#[test]
+fn fbm_octaves_follow_lacunarity_and_persistence() {
+    let fbm = Fbm::new(noise())
+        .with_octaves(3)
+        .with_lacunarity(3.0)
+        .with_persistence(0.25);
+    let noise = noise();
+
+    for (x, y, _) in points() {
+        let expected = (noise.eval_2d(x, y)
+            + 0.25 * noise.eval_2d(x * 3.0, y * 3.0)
+            + 0.0625 * noise.eval_2d(x * 9.0, y * 9.0))
+            / 1.3125;
+        assert!((fbm.eval_2d(x, y) - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn fbm_seed_offset_shifts_every_octave() {
+    let fbm = Fbm::new(noise()).with_octaves(2).with_seed_offset(10.5);
+    let noise = noise();
+
+    for (x, y, _) in points() {
+        let expected =
+            (noise.eval_2d(x, y) + 0.5 * noise.eval_2d(x * 2.0 + 10.5, y * 2.0 + 10.5)) / 1.5;
+        assert!((fbm.eval_2d(x, y) - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn fbm_stays_in_range() {
+    for &octaves in &[1, 4, 8] {
+        let fbm = Fbm::new(noise()).with_octaves(octaves);
+        let values: Vec<f64> = points()
+            .flat_map(|(x, y, z)| {
+                vec![
+                    fbm.eval_2d(x, y),
+                    fbm.eval_3d(x, y, z),
+                    fbm.eval_4d(x, y, z, x - y),
+                ]
+            })
+            .collect();
+
+        assert!(values.iter().all(|value| value.abs() <= 1.0));
+        assert!(values.iter().any(|&value| value > 0.3));
+        assert!(values.iter().any(|&value| value < -0.3));
+    }
+}
+
+#[test]
+#[should_panic]
+fn fbm_needs_an_octave() {
+    Fbm::new(noise()).with_octaves(0);
+}
+
+#[test]
+fn billow_with_one_octave_folds_the_noise() {
+    let billow = Billow::new(noise()).with_octaves(1);
+    let noise = noise();
+
+    for (x, y, z) in points() {
+        assert_eq!(billow.eval_2d(x, y), 2.0 * noise.eval_2d(x, y).abs() - 1.0);
+        assert_eq!(
+            billow.eval_3d(x, y, z),
+            2.0 * noise.eval_3d(x, y, z).abs() - 1.0
+        );
+    }
+}
+
+#[test]
+fn billow_octaves_follow_lacunarity_and_persistence() {
+    let billow = Billow::new(noise())
+        .with_octaves(2)
+        .with_lacunarity(1.5)
+        .with_persistence(0.75);
+    let noise = noise();
+    let fold = |value: f64| 2.0 * value.abs() - 1.0;
+
+    for (x, y, _) in points() {
+        let expected =
+            (fold(noise.eval_2d(x, y)) + 0.75 * fold(noise.eval_2d(x * 1.5, y * 1.5))) / 1.75;
+        assert!((billow.eval_2d(x, y) - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn billow_stays_in_range() {
+    let billow = Billow::new(noise()).with_octaves(6);
+    let values: Vec<f64> = points()
+        .flat_map(|(x, y, z)| {
+            vec![
+                billow.eval_2d(x, y),
+                billow.eval_3d(x, y, z),
+                billow.eval_4d(x, y, z, x - y),
+            ]
+        })
+        .collect();
+
+    assert!(values.iter().all(|value| value.abs() <= 1.0));
+    assert!(values.iter().any(|&value| value > 0.0));
+    assert!(values.iter().any(|&value| value < -0.5));
+}
+
+#[test]
+fn fractals_are_deterministic_per_seed() {
+    let other = OpenSimplexNoise::new(Some(SEED + 1));
+    let (fbm, same_fbm, other_fbm) = (
+        Fbm::new(noise()),
+        Fbm::new(noise()),
+        Fbm::new(OpenSimplexNoise::new(Some(SEED + 1))),
+    );
+    let (billow, other_billow) = (Billow::new(noise()), Billow::new(other));
+
+    let mut differs = false;
+    for (x, y, z) in points() {
+        assert_eq!(fbm.eval_3d(x, y, z), same_fbm.eval_3d(x, y, z));
+        differs |= fbm.eval_3d(x, y, z) != other_fbm.eval_3d(x, y, z);
+        differs |= billow.eval_3d(x, y, z) != other_billow.eval_3d(x, y, z);
+    }
+    assert!(differs);
+}
+
+#[test]
+fn ridged_stays_in_range() {
+    for &offset in &[0.5, 0.8, 1.0, 1.5] {
+        for &gain in &[0.5, 2.0, 4.0] {
+            let ridged = RidgedMulti::new(noise())
+                .with_octaves(5)
+                .with_offset(offset)
+                .with_gain(gain);
+
+            for (x, y, z) in points() {
+                assert!(ridged.eval_2d(x, y).abs() <= 1.0);
+                assert!(ridged.eval_3d(x, y, z).abs() <= 1.0);
+                assert!(ridged.eval_4d(x, y, z, x - y).abs() <= 1.0);
+            }
+        }
+    }
+}
+
+#[test]
+fn ridged_spreads_over_the_range() {
+    let ridged = RidgedMulti::new(noise());
+    let values: Vec<f64> = points().map(|(x, y, _)| ridged.eval_2d(x, y)).collect();
+
+    assert!(values.iter().any(|&value| value > 0.0));
+    assert!(values.iter().any(|&value| value < -0.5));
+}
+
+#[test]
+#[should_panic]
+fn ridged_rejects_a_zero_offset() {
+    RidgedMulti::new(noise()).with_offset(0.0);
+}
+
+#[test]
+#[should_panic]
+fn ridged_rejects_offsets_below_one_half() {
+    RidgedMulti::new(noise()).with_offset(0.4);
+}
+
+#[test]
+fn fractals_take_the_hashed_generator() {
+    let hashed = || HashedOpenSimplexNoise::new(Some(SEED));
+    let fbm = Fbm::new(hashed()).with_octaves(1).with_frequency(2.5);
+    let billow = Billow::new(hashed()).with_octaves(1);
+    let ridged = RidgedMulti::new(hashed()).with_octaves(1);
+    let noise = hashed();
+
+    for (x, y, z) in points() {
+        assert_eq!(
+            fbm.eval_3d(x, y, z),
+            noise.eval_3d(x * 2.5, y * 2.5, z * 2.5)
+        );
+        assert_eq!(billow.eval_2d(x, y), 2.0 * noise.eval_2d(x, y).abs() - 1.0);
+        let signal = 1.0 - noise.eval_4d(x, y, z, x - y).abs();
+        assert_eq!(ridged.eval_4d(x, y, z, x - y), signal * signal * 2.0 - 1.0);
+    }
+}
@@ -0,0 +1,544 @@
+use opensimplex_noise_rs::{
+    HashPeriod, HashedOpenSimplexNoise, LatticeHash, NoisePoint, NoiseVersion, OpenSimplex2F,
+    OpenSimplex2S, OpenSimplexGenerator, OpenSimplexNoise, PointHash, Vec1, Vec2, Vec3, Vec4, VecN,
+};
+
+// Every released version has to keep producing exactly the same values. When one of these
+// hashes changes, the change belongs in a new `NoiseVersion` instead.
+const SEEDS: [i64; 3] = [0, 883_279_212_983_182_319, -5];
+const PERIODS: [HashPeriod; 3] = [
+    HashPeriod::Cells2048,
+    HashPeriod::Cells65536,
+    HashPeriod::Unbounded,
+];
+
+// Output paths of the generators, in the order `generator_hashes` returns them
+const PATHS: [&str; 9] = [
+    "1D",
+    "2D",
+    "3D",
+    "4D",
+    "5D",
+    "6D",
+    "eval_nd in 2D to 4D",
+    "gradients",
+    "f32",
+];
+const PATHS_2F: [&str; 3] = ["2D", "3D", "4D"];
+const PATHS_2S: [&str; 5] = ["2D", "3D", "3D improve XY", "3D improve XZ", "4D"];
+
+// A step that doesn't line up with the lattice, and a longer one spreading the few samples of
+// the 5D and 6D grids over more cells
+const STEP: f64 = 0.173;
+const ND_STEP: f64 = 1.37;
+
+// FNV-1a over the bit patterns of the samples
+fn hash(values: impl Iterator<Item = f64>) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for value in values {
+        for byte in value.to_bits().to_le_bytes().iter() {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+// `side^N` points spanning negative and positive coordinates, the first axis varying fastest
+fn grid<const N: usize>(side: u32, step: f64) -> impl Iterator<Item = [f64; N]> {
+    (0..side.pow(N as u32)).map(move |mut i| {
+        let mut point = [0.0; N];
+        for coord in point.iter_mut() {
+            *coord = f64::from(i % side) * step - 11.0;
+            i /= side;
+        }
+        point
+    })
+}
+
+fn push_with_gradient<const N: usize, H: PointHash>(
+    outputs: &mut Vec<f64>,
+    noise: &OpenSimplexGenerator<H>,
+    side: u32,
+    step: f64,
+) where
+    [f64; N]: NoisePoint<Gradient = [f64; N]>,
+{
+    for point in grid::<N>(side, step) {
+        let (value, gradient) = noise.eval_with_gradient(point);
+        outputs.push(value);
+        outputs.extend_from_slice(&gradient);
+    }
+}
+
+fn push_nd_with_gradient<const N: usize, H: PointHash + LatticeHash<VecN<f64, N>>>(
+    outputs: &mut Vec<f64>,
+    noise: &OpenSimplexGenerator<H>,
+    side: u32,
+) {
+    for point in grid::<N>(side, STEP) {
+        let (value, gradient) = noise.eval_nd_with_gradient(point);
+        outputs.push(value);
+        outputs.extend_from_slice(&gradient);
+    }
+}
+
+// Values and gradients of every dimension on coarser grids, along with `eval_nd` where it
+// doesn't take the same path as `eval`
+fn gradients<H>(noise: &OpenSimplexGenerator<H>) -> Vec<f64>
+where
+    H: PointHash
+        + LatticeHash<VecN<f64, 2>>
+        + LatticeHash<VecN<f64, 3>>
+        + LatticeHash<VecN<f64, 4>>,
+{
+    let mut outputs = Vec::new();
+    push_with_gradient::<1, _>(&mut outputs, noise, 512, STEP);
+    push_with_gradient::<2, _>(&mut outputs, noise, 32, STEP);
+    push_with_gradient::<3, _>(&mut outputs, noise, 10, STEP);
+    push_with_gradient::<4, _>(&mut outputs, noise, 6, STEP);
+    push_with_gradient::<5, _>(&mut outputs, noise, 3, ND_STEP);
+    push_with_gradient::<6, _>(&mut outputs, noise, 2, ND_STEP);
+    push_nd_with_gradient::<2, _>(&mut outputs, noise, 32);
+    push_nd_with_gradient::<3, _>(&mut outputs, noise, 10);
+    push_nd_with_gradient::<4, _>(&mut outputs, noise, 6);
+    outputs
+}
+
+// Values and gradients of the single precision path, widening keeps their bits
+fn f32_outputs<H>(noise: &OpenSimplexGenerator<H>) -> Vec<f64>
+where
+    H: PointHash
+        + LatticeHash<Vec1<f32>>
+        + LatticeHash<Vec2<f32>>
+        + LatticeHash<Vec3<f32>>
+        + LatticeHash<Vec4<f32>>,
+{
+    let mut outputs = Vec::new();
+    for [x] in grid::<1>(512, STEP) {
+        let x = x as f32;
+        let (value, gradient) = noise.eval_1d_with_gradient_f32(x);
+        outputs.extend_from_slice(&[noise.eval_1d_f32(x), value, gradient]);
+    }
+    for [x, y] in grid::<2>(32, STEP) {
+        let (x, y) = (x as f32, y as f32);
+        let (value, gradient) = noise.eval_2d_with_gradient_f32(x, y);
+        outputs.extend_from_slice(&[noise.eval_2d_f32(x, y), value]);
+        outputs.extend_from_slice(&gradient);
+    }
+    for [x, y, z] in grid::<3>(10, STEP) {
+        let (x, y, z) = (x as f32, y as f32, z as f32);
+        let (value, gradient) = noise.eval_3d_with_gradient_f32(x, y, z);
+        outputs.extend_from_slice(&[noise.eval_3d_f32(x, y, z), value]);
+        outputs.extend_from_slice(&gradient);
+    }
+    for [x, y, z, w] in grid::<4>(6, STEP) {
+        let (x, y, z, w) = (x as f32, y as f32, z as f32, w as f32);
+        let (value, gradient) = noise.eval_4d_with_gradient_f32(x, y, z, w);
+        outputs.extend_from_slice(&[noise.eval_4d_f32(x, y, z, w), value]);
+        outputs.extend_from_slice(&gradient);
+    }
+    outputs.into_iter().map(f64::from).collect()
+}
+
+fn generator_hashes<H>(noise: &OpenSimplexGenerator<H>) -> [u64; 9]
+where
+    H: PointHash
+        + LatticeHash<VecN<f64, 2>>
+        + LatticeHash<VecN<f64, 3>>
+        + LatticeHash<VecN<f64, 4>>
+        + LatticeHash<Vec1<f32>>
+        + LatticeHash<Vec2<f32>>
+        + LatticeHash<Vec3<f32>>
+        + LatticeHash<Vec4<f32>>,
+{
+    let eval_nd = grid::<2>(64, STEP)
+        .map(|point| noise.eval_nd(point))
+        .chain(grid::<3>(16, STEP).map(|point| noise.eval_nd(point)))
+        .chain(grid::<4>(6, STEP).map(|point| noise.eval_nd(point)));
+    [
+        hash(grid::<1>(4096, STEP).map(|point| noise.eval(point))),
+        hash(grid::<2>(256, STEP).map(|point| noise.eval(point))),
+        hash(grid::<3>(48, STEP).map(|point| noise.eval(point))),
+        hash(grid::<4>(20, STEP).map(|point| noise.eval(point))),
+        hash(grid::<5>(5, ND_STEP).map(|point| noise.eval(point))),
+        hash(grid::<6>(3, ND_STEP).map(|point| noise.eval(point))),
+        hash(eval_nd),
+        hash(gradients(noise).into_iter()),
+        hash(f32_outputs(noise).into_iter()),
+    ]
+}
+
+fn check(name: &str, paths: &[&str], actual: &[u64], expected: &[u64]) {
+    for ((path, actual), expected) in paths.iter().zip(actual).zip(expected) {
+        assert_eq!(actual, expected, "{}, {}", name, path);
+    }
+}
+
+fn check_seeds<H>(
+    name: &str,
+    generator: impl Fn(i64) -> OpenSimplexGenerator<H>,
+    expected: [[u64; 9]; 3],
+) where
+    H: PointHash
+        + LatticeHash<VecN<f64, 2>>
+        + LatticeHash<VecN<f64, 3>>
+        + LatticeHash<VecN<f64, 4>>
+        + LatticeHash<Vec1<f32>>
+        + LatticeHash<Vec2<f32>>
+        + LatticeHash<Vec3<f32>>
+        + LatticeHash<Vec4<f32>>,
+{
+    for (&seed, expected) in SEEDS.iter().zip(expected.iter()) {
+        let actual = generator_hashes(&generator(seed));
+        check(
+            &format!("{}, seed {}", name, seed),
+            &PATHS,
+            &actual,
+            expected,
+        );
+    }
+}
+
+// The longer periods with the seed in the middle of `SEEDS`
+fn check_periods(version: NoiseVersion, expected: [[u64; 9]; 3]) {
+    for (&period, expected) in PERIODS.iter().zip(expected.iter()) {
+        let noise =
+            OpenSimplexNoise::with_version(Some(SEEDS[1]), version).with_hash_period(period);
+        let name = format!("{:?}, {:?}", version, period);
+        check(&name, &PATHS, &generator_hashes(&noise), expected);
+    }
+}
+
+#[test]
+fn v0_3_output_is_unchanged() {
+    check_seeds(
+        "V0_3",
+        |seed| OpenSimplexNoise::with_version(Some(seed), NoiseVersion::V0_3),
+        [
+            [
+                0x3da1_cbd9_ea24_2ea8,
+                0x3f81_435f_81ec_1f01,
+                0x3a85_df8b_aa97_6fd9,
+                0x5435_f46d_53c4_5f18,
+                0x6765_69c2_0f1d_bd17,
+                0xb3cf_65ff_10cc_849e,
+                0x86a8_fa48_5751_370a,
+                0x4e67_e632_85c0_8064,
+                0x5d4f_39e3_3d5e_99ad,
+            ],
+            [
+                0xee20_6ede_0bb7_21ef,
+                0xe564_16d3_53f0_276d,
+                0xffb5_35de_2631_addf,
+                0xf95e_7e3c_7cc5_6aaa,
+                0x645f_569b_627b_18da,
+                0x2081_f34d_1ceb_ba29,
+                0x0dff_9001_551d_d5a2,
+                0x532e_f31d_e9fa_4503,
+                0x4567_fe30_c4ba_6c81,
+            ],
+            [
+                0x64dd_2e3a_72a0_d9f6,
+                0x6f82_625e_0bfb_fadf,
+                0x71eb_d6f7_a06d_4b20,
+                0x390b_f115_f343_5be4,
+                0x25e5_0a2d_61a0_8a4b,
+                0xe045_ad96_f6ac_da67,
+                0x1d8b_055a_13a9_cc23,
+                0x8ec8_b591_44e2_650e,
+                0x65d7_bbe4_0f63_4685,
+            ],
+        ],
+    );
+}
+
+#[test]
+fn v0_4_output_is_unchanged() {
+    check_seeds(
+        "V0_4",
+        |seed| OpenSimplexNoise::with_version(Some(seed), NoiseVersion::V0_4),
+        [
+            [
+                0xe03f_1c81_08e7_50bd,
+                0xda23_3f10_c290_b081,
+                0xfecb_39f6_4ffb_a570,
+                0x8abd_ab6e_22ff_4646,
+                0x8e62_7ba6_036a_b4dc,
+                0xb92b_455b_ec57_15f0,
+                0xe95d_40ae_97b1_5a66,
+                0x8875_89ab_69b4_2f9d,
+                0x1b06_67e7_71f8_a76d,
+            ],
+            [
+                0x663e_e5f4_e08d_ba7d,
+                0x46e1_49a4_d9e1_02c4,
+                0x9aba_8a88_7a98_fccd,
+                0xc0a7_4f7f_7b2b_986d,
+                0xab3c_c7e9_68ae_e3ef,
+                0x2146_299a_c2a0_acd6,
+                0x8b25_a863_1012_08ac,
+                0x95e0_241d_8ba0_8a31,
+                0xd0ce_4d09_aaea_2975,
+            ],
+            [
+                0xda4b_6a3f_fc67_17db,
+                0xf630_36d4_8ed8_14ea,
+                0x8094_fe07_e014_408f,
+                0xfdfe_233c_d33f_c95a,
+                0xffb6_44e2_dd9f_3b55,
+                0xbef2_441f_791b_e13e,
+                0x13eb_c363_b7d3_c38c,
+                0x4f0d_21b3_ff98_d034,
+                0x008b_901d_b058_ddd6,
+            ],
+        ],
+    );
+}
+
+#[test]
+fn v0_3_hash_periods_are_unchanged() {
+    check_periods(
+        NoiseVersion::V0_3,
+        [
+            [
+                0xf250_a9d7_f8ad_6039,
+                0x7408_6e58_e8fe_da67,
+                0xdcf5_6b73_0908_f619,
+                0x9ade_e313_13e8_c41e,
+                0xf012_a888_9fe3_4cdc,
+                0x620a_5bd7_c667_c099,
+                0x3ad0_9036_7bc1_93bd,
+                0xb97e_e9b1_24b3_1138,
+                0xcc0d_e17d_afd0_b71a,
+            ],
+            [
+                0xb77a_229f_3248_c4d3,
+                0xfa1e_e29d_ba4c_44d2,
+                0xffb1_d554_20f7_88e0,
+                0x70bd_7878_74a8_22bb,
+                0x55b5_1f36_086b_c3a5,
+                0xad99_f36a_9735_8067,
+                0xfeb6_4597_3619_d4cf,
+                0x7d25_c349_9124_df22,
+                0x7215_147a_437a_b387,
+            ],
+            [
+                0xa172_77c3_93f7_ec6f,
+                0x7cb0_c31f_b663_ca45,
+                0xf8af_7a10_78da_d0bc,
+                0x28e8_dec9_869c_b737,
+                0xda52_5d43_9bc1_520b,
+                0xc29f_4e68_ce51_5afc,
+                0x2953_e7a7_2a7b_7895,
+                0x940b_6488_2d31_a93c,
+                0x9c7f_b7ae_990a_aa63,
+            ],
+        ],
+    );
+}
+
+#[test]
+fn v0_4_hash_periods_are_unchanged() {
+    check_periods(
+        NoiseVersion::V0_4,
+        [
+            [
+                0x57de_a639_a2f7_7155,
+                0x93ab_d8ab_b9e2_4ec7,
+                0x7cf2_6105_ba59_1937,
+                0x69b7_0061_8a66_8b57,
+                0xe4ae_1345_8843_d9b4,
+                0x4dfe_6541_8039_3d1e,
+                0x945e_200f_faf8_cbc5,
+                0x5c65_308f_a2ce_b491,
+                0xf330_ea23_752c_8352,
+            ],
+            [
+                0xb77a_229f_3248_c4d3,
+                0xd100_a0d2_4bb4_2642,
+                0x1240_41e9_048d_de93,
+                0x26b8_6986_c8d3_2979,
+                0x55b5_1f36_086b_c3a5,
+                0xad99_f36a_9735_8067,
+                0xfeb6_4597_3619_d4cf,
+                0xfcf6_ccc1_d6eb_39f7,
+                0x810f_3fc9_7ab2_9f18,
+            ],
+            [
+                0xa172_77c3_93f7_ec6f,
+                0x596d_7b46_6fbc_b050,
+                0x22a5_8c43_eb8f_0733,
+                0x6347_8320_857b_d69f,
+                0xda52_5d43_9bc1_520b,
+                0xc29f_4e68_ce51_5afc,
+                0x2953_e7a7_2a7b_7895,
+                0x0151_54c7_e7a8_3fc2,
+                0xe8fd_3692_b9f2_b1d6,
+            ],
+        ],
+    );
+}
+
+#[test]
+fn hashed_v0_3_output_is_unchanged() {
+    check_seeds(
+        "hashed V0_3",
+        |seed| HashedOpenSimplexNoise::with_version(Some(seed), NoiseVersion::V0_3),
+        [
+            [
+                0x2d40_6917_4a07_9945,
+                0x94af_e78f_cf3c_9b3a,
+                0x237a_24e6_862d_619d,
+                0xec4e_6542_cc58_1312,
+                0x4527_e789_9c0c_b813,
+                0xe96c_e7e1_1072_f766,
+                0x9c73_491d_4e56_43a6,
+                0x2273_db85_c6ca_90e1,
+                0xbb92_a3b9_20a9_21b4,
+            ],
+            [
+                0xa172_77c3_93f7_ec6f,
+                0x7cb0_c31f_b663_ca45,
+                0xf8af_7a10_78da_d0bc,
+                0x28e8_dec9_869c_b737,
+                0xda52_5d43_9bc1_520b,
+                0xc29f_4e68_ce51_5afc,
+                0x2953_e7a7_2a7b_7895,
+                0x940b_6488_2d31_a93c,
+                0x9c7f_b7ae_990a_aa63,
+            ],
+            [
+                0xd574_c46d_c537_c6a3,
+                0x6660_92ee_72b5_b1b8,
+                0x52c2_548b_49cd_dd14,
+                0x5ee6_1b3b_9b15_aae1,
+                0x58c1_72f8_0ade_d278,
+                0xdfb7_ad6c_4605_2a90,
+                0x5d16_95a5_bab9_d9f8,
+                0xf342_17cf_500b_0cdf,
+                0xd8c1_560e_704f_e323,
+            ],
+        ],
+    );
+}
+
+#[test]
+fn hashed_v0_4_output_is_unchanged() {
+    check_seeds(
+        "hashed V0_4",
+        |seed| HashedOpenSimplexNoise::with_version(Some(seed), NoiseVersion::V0_4),
+        [
+            [
+                0x2d40_6917_4a07_9945,
+                0xd1e9_ecde_73a6_cc89,
+                0x6814_7812_5301_3d95,
+                0x5ea5_6c79_edbd_199a,
+                0x4527_e789_9c0c_b813,
+                0xe96c_e7e1_1072_f766,
+                0x9c73_491d_4e56_43a6,
+                0x779d_317e_9d96_b4b4,
+                0x993a_6e3f_3f8e_85dd,
+            ],
+            [
+                0xa172_77c3_93f7_ec6f,
+                0x596d_7b46_6fbc_b050,
+                0x22a5_8c43_eb8f_0733,
+                0x6347_8320_857b_d69f,
+                0xda52_5d43_9bc1_520b,
+                0xc29f_4e68_ce51_5afc,
+                0x2953_e7a7_2a7b_7895,
+                0x0151_54c7_e7a8_3fc2,
+                0xe8fd_3692_b9f2_b1d6,
+            ],
+            [
+                0xd574_c46d_c537_c6a3,
+                0x45ee_1bd3_cda2_21e6,
+                0x6c2b_6e37_cb42_fb36,
+                0x4e7d_e811_fe09_7d5b,
+                0x58c1_72f8_0ade_d278,
+                0xdfb7_ad6c_4605_2a90,
+                0x5d16_95a5_bab9_d9f8,
+                0x160c_20f5_41c6_4e2f,
+                0x3806_7670_5642_71ab,
+            ],
+        ],
+    );
+}
+
+#[test]
+fn open_simplex_2f_output_is_unchanged() {
+    let expected: [[u64; 3]; 3] = [
+        [
+            0xdb20_1628_3e92_952e,
+            0x255c_3561_7ed6_156a,
+            0x8ef5_f1f8_30bb_4996,
+        ],
+        [
+            0x389f_e065_0d59_d621,
+            0xf224_eaf3_ad12_d57d,
+            0x4447_f6df_5243_2136,
+        ],
+        [
+            0x2283_7d65_73a2_f371,
+            0xdecc_ef4a_a671_3c38,
+            0x7a64_90bf_a8b7_b136,
+        ],
+    ];
+    for (&seed, expected) in SEEDS.iter().zip(expected.iter()) {
+        let noise = OpenSimplex2F::new(Some(seed));
+        let actual = [
+            hash(grid(128, STEP).map(|[x, y]| noise.eval_2d(x, y))),
+            hash(grid(24, STEP).map(|[x, y, z]| noise.eval_3d(x, y, z))),
+            hash(grid(12, STEP).map(|[x, y, z, w]| noise.eval_4d(x, y, z, w))),
+        ];
+        check(&format!("2F, seed {}", seed), &PATHS_2F, &actual, expected);
+    }
+}
+
+#[test]
+fn open_simplex_2s_output_is_unchanged() {
+    let expected: [[u64; 5]; 3] = [
+        [
+            0x2f54_6b47_4656_1c7b,
+            0x676b_2927_6cf1_1d73,
+            0xc466_daf9_b412_e61e,
+            0x5d51_caa4_da56_425d,
+            0x0e53_8891_bc50_0dc3,
+        ],
+        [
+            0x8378_8de6_ca0b_9e85,
+            0x7674_631c_b1c1_da64,
+            0xb62e_e1a8_5b37_e5f0,
+            0x4dbb_577a_d07b_7d42,
+            0x8322_f9de_801b_0230,
+        ],
+        [
+            0xe8ef_f013_a52b_e979,
+            0xffe8_6202_3f4a_d058,
+            0xb2f7_89c4_5e82_c346,
+            0x6e97_1c6c_bab6_b42b,
+            0x0e78_7e52_d3fa_8efc,
+        ],
+    ];
+    for (&seed, expected) in SEEDS.iter().zip(expected.iter()) {
+        let noise = OpenSimplex2S::new(Some(seed));
+        let actual = [
+            hash(grid(128, STEP).map(|[x, y]| noise.eval_2d(x, y))),
+            hash(grid(24, STEP).map(|[x, y, z]| noise.eval_3d(x, y, z))),
+            hash(grid(24, STEP).map(|[x, y, z]| noise.eval_3d_improve_xy(x, y, z))),
+            hash(grid(24, STEP).map(|[x, y, z]| noise.eval_3d_improve_xz(x, y, z))),
+            hash(grid(12, STEP).map(|[x, y, z, w]| noise.eval_4d(x, y, z, w))),
+        ];
+        check(&format!("2S, seed {}", seed), &PATHS_2S, &actual, expected);
+    }
+}
+
+#[test]
+fn new_uses_v0_3() {
+    assert_eq!(
+        OpenSimplexNoise::new(None).version(),
+        NoiseVersion::default()
+    );
+    assert_eq!(NoiseVersion::default(), NoiseVersion::V0_3);
+}
@@ -0,0 +1,138 @@
+use opensimplex_noise_rs::{NoiseVersion, OpenSimplexNoise};
+
+const SEED: i64 = 883_279_212_983_182_319;
+const POINTS: usize = 2000;
+
+// Scattered points in [-64, 64)^N
+fn points<const N: usize>(salt: u64) -> impl Iterator<Item = [f64; N]> {
+    let mut state = salt;
+    (0..POINTS).map(move |_| {
+        let mut point = [0.0; N];
+        for coord in point.iter_mut() {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            *coord = (state >> 11) as f64 / (1u64 << 53) as f64 * 128.0 - 64.0;
+        }
+        point
+    })
+}
+
+// Compares the analytic gradient with central differences along every axis
+fn check_f64<const N: usize>(
+    eval: impl Fn([f64; N]) -> f64,
+    eval_with_gradient: impl Fn([f64; N]) -> (f64, [f64; N]),
+) {
+    let step = 1e-6;
+    for point in points::<N>(N as u64) {
+        let (value, gradient) = eval_with_gradient(point);
+        assert_eq!(value, eval(point));
+
+        for axis in 0..N {
+            let (mut ahead, mut behind) = (point, point);
+            ahead[axis] += step;
+            behind[axis] -= step;
+            let estimate = (eval(ahead) - eval(behind)) / (2.0 * step);
+            assert!(
+                (gradient[axis] - estimate).abs() < 1e-5,
+                "axis {} of {:?}: {} against {}",
+                axis,
+                point,
+                gradient[axis],
+                estimate
+            );
+        }
+    }
+}
+
+// Single precision can't resolve small steps, so the step and the tolerance are coarser. The
+// steps would cross the jumps of `V0_3`, so only the continuous versions are checked.
+fn check_f32<const N: usize>(
+    eval: impl Fn([f32; N]) -> f32,
+    eval_with_gradient: impl Fn([f32; N]) -> (f32, [f32; N]),
+) {
+    let step = 1e-2;
+    for point in points::<N>(N as u64 + 100) {
+        let point = point.map(|coord| coord as f32);
+        let (value, gradient) = eval_with_gradient(point);
+        assert_eq!(value, eval(point));
+
+        for axis in 0..N {
+            let (mut ahead, mut behind) = (point, point);
+            ahead[axis] += step;
+            behind[axis] -= step;
+            let estimate = (eval(ahead) - eval(behind)) / (2.0 * step);
+            assert!(
+                (gradient[axis] - estimate).abs() < 2e-2,
+                "axis {} of {:?}: {} against {}",
+                axis,
+                point,
+                gradient[axis],
+                estimate
+            );
+        }
+    }
+}
+
+fn versions() -> impl Iterator<Item = OpenSimplexNoise> {
+    [NoiseVersion::V0_3, NoiseVersion::V0_4]
+        .iter()
+        .map(|&version| OpenSimplexNoise::with_version(Some(SEED), version))
+}
+
+#[test]
+fn gradient_2d_matches_finite_differences() {
+    for noise in versions() {
+        check_f64(
+            |[x, y]| noise.eval_2d(x, y),
+            |[x, y]| noise.eval_2d_with_gradient(x, y),
+        );
+    }
+}
+
+#[test]
+fn gradient_3d_matches_finite_differences() {
+    for noise in versions() {
+        check_f64(
+            |[x, y, z]| noise.eval_3d(x, y, z),
+            |[x, y, z]| noise.eval_3d_with_gradient(x, y, z),
+        );
+    }
+}
+
+#[test]
+fn gradient_4d_matches_finite_differences() {
+    for noise in versions() {
+        check_f64(
+            |[x, y, z, w]| noise.eval_4d(x, y, z, w),
+            |[x, y, z, w]| noise.eval_4d_with_gradient(x, y, z, w),
+        );
+    }
+}
+
+#[test]
+fn f32_gradient_2d_matches_finite_differences() {
+    let noise = OpenSimplexNoise::with_version(Some(SEED), NoiseVersion::V0_4);
+    check_f32(
+        |[x, y]| noise.eval_2d_f32(x, y),
+        |[x, y]| noise.eval_2d_with_gradient_f32(x, y),
+    );
+}
+
+#[test]
+fn f32_gradient_3d_matches_finite_differences() {
+    let noise = OpenSimplexNoise::with_version(Some(SEED), NoiseVersion::V0_4);
+    check_f32(
+        |[x, y, z]| noise.eval_3d_f32(x, y, z),
+        |[x, y, z]| noise.eval_3d_with_gradient_f32(x, y, z),
+    );
+}
+
+#[test]
+fn f32_gradient_4d_matches_finite_differences() {
+    let noise = OpenSimplexNoise::with_version(Some(SEED), NoiseVersion::V0_4);
+    check_f32(
+        |[x, y, z, w]| noise.eval_4d_f32(x, y, z, w),
+        |[x, y, z, w]| noise.eval_4d_with_gradient_f32(x, y, z, w),
+    );
+}
@@ -0,0 +1,43 @@
+use opensimplex_noise_rs::{HashPeriod, OpenSimplexNoise};
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+// In 3D a shift by (2, 2, 2) is a shift by (1, 1, 1) on the lattice, so `period` cells along
+// every lattice axis are `2 * period` units along the main diagonal.
+fn repeats_after(noise: &OpenSimplexNoise, cells: i64) -> bool {
+    let shift = 2.0 * cells as f64;
+    (0..256).all(|i| {
+        let (x, y, z) = (i as f64 * 0.173, i as f64 * -0.091, i as f64 * 0.057);
+        let value = noise.eval_3d(x, y, z);
+        (value - noise.eval_3d(x + shift, y + shift, z + shift)).abs() < 1e-6
+    })
+}
+
+#[test]
+fn gradients_repeat_after_the_hash_period() {
+    let periods = [
+        (HashPeriod::Cells256, 256),
+        (HashPeriod::Cells2048, 2048),
+        (HashPeriod::Cells65536, 65536),
+    ];
+    for &(period, cells) in &periods {
+        let noise = OpenSimplexNoise::new(Some(SEED)).with_hash_period(period);
+        assert!(repeats_after(&noise, cells), "{:?}", period);
+        assert!(!repeats_after(&noise, cells / 2), "{:?}", period);
+    }
+
+    let noise = OpenSimplexNoise::new(Some(SEED)).with_hash_period(HashPeriod::Unbounded);
+    assert!(!repeats_after(&noise, 65536));
+}
+
+#[test]
+fn default_period_is_the_legacy_one() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let legacy = OpenSimplexNoise::new(Some(SEED)).with_hash_period(HashPeriod::Cells256);
+    assert_eq!(noise.hash_period(), HashPeriod::Cells256);
+
+    for i in 0..256 {
+        let (x, y) = (i as f64 * 0.173, i as f64 * -0.091);
+        assert_eq!(noise.eval_2d(x, y), legacy.eval_2d(x, y));
+    }
+}
@@ -0,0 +1,46 @@
+use opensimplex_noise_rs::{HashPeriod, HashedOpenSimplexNoise, NoiseVersion, OpenSimplexNoise};
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+#[test]
+fn hashed_matches_unbounded_hash_period() {
+    for &version in &[NoiseVersion::V0_3, NoiseVersion::V0_4] {
+        let hashed = HashedOpenSimplexNoise::with_version(Some(SEED), version);
+        let table = OpenSimplexNoise::with_version(Some(SEED), version)
+            .with_hash_period(HashPeriod::Unbounded);
+
+        for i in 0..1000 {
+            let (x, y, z, w) = (
+                i as f64 * 0.173,
+                i as f64 * -0.091,
+                i as f64 * 0.057,
+                i as f64 * -0.033,
+            );
+            assert_eq!(hashed.eval_2d(x, y), table.eval_2d(x, y));
+            assert_eq!(hashed.eval_3d(x, y, z), table.eval_3d(x, y, z));
+            assert_eq!(hashed.eval_4d(x, y, z, w), table.eval_4d(x, y, z, w));
+            assert_eq!(
+                hashed.eval_3d_with_gradient(x, y, z),
+                table.eval_3d_with_gradient(x, y, z)
+            );
+            assert_eq!(
+                hashed.eval_5d(x, y, z, w, 0.5),
+                table.eval_5d(x, y, z, w, 0.5)
+            );
+            assert_eq!(
+                hashed.eval_3d_f32(x as f32, y as f32, z as f32),
+                table.eval_3d_f32(x as f32, y as f32, z as f32)
+            );
+        }
+    }
+}
+
+#[test]
+fn seeds_give_different_noise() {
+    let first = HashedOpenSimplexNoise::new(Some(1));
+    let second = HashedOpenSimplexNoise::new(Some(2));
+    assert!((0..100).any(|i| {
+        let x = i as f64 * 0.173;
+        first.eval_2d(x, 0.5) != second.eval_2d(x, 0.5)
+    }));
+}
@@ -0,0 +1,49 @@
+use opensimplex_noise_rs::{HashedOpenSimplexNoise, OpenSimplexNoise};
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+#[test]
+fn animation_loops() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let loop_length = 3.5;
+    for i in 0..256 {
+        let (x, y, t) = (i as f64 * 0.173, i as f64 * -0.091, i as f64 * 0.057);
+        let value = noise.eval_2d_looping(x, y, t, loop_length, 1.2);
+
+        assert!(
+            (value - noise.eval_2d_looping(x, y, t + loop_length, loop_length, 1.2)).abs() < 1e-9
+        );
+        assert_ne!(
+            value,
+            noise.eval_2d_looping(x, y, t + 0.5, loop_length, 1.2)
+        );
+    }
+}
+
+#[test]
+fn torus_tiles_on_both_axes() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let period = [10.0, 6.5];
+    let radius = [1.6, 1.0];
+    for i in 0..256 {
+        let (x, y) = (i as f64 * 0.173, i as f64 * -0.091);
+        let value = noise.eval_2d_torus(x, y, period, radius);
+
+        assert!((value - noise.eval_2d_torus(x + period[0], y, period, radius)).abs() < 1e-9);
+        assert!((value - noise.eval_2d_torus(x, y - period[1], period, radius)).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn hashed_animation_and_torus_loop() {
+    let noise = HashedOpenSimplexNoise::new(Some(SEED));
+    let (period, radius) = ([10.0, 6.5], [1.6, 1.0]);
+    for i in 0..256 {
+        let (x, y, t) = (i as f64 * 0.173, i as f64 * -0.091, i as f64 * 0.057);
+        let value = noise.eval_2d_looping(x, y, t, 3.5, 1.2);
+        assert!((value - noise.eval_2d_looping(x, y, t + 3.5, 3.5, 1.2)).abs() < 1e-9);
+
+        let value = noise.eval_2d_torus(x, y, period, radius);
+        assert!((value - noise.eval_2d_torus(x + period[0], y, period, radius)).abs() < 1e-9);
+    }
+}
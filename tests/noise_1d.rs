@@ -0,0 +1,35 @@
+use opensimplex_noise_rs::OpenSimplexNoise;
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+#[test]
+fn values_stay_in_range() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let values: Vec<f64> = (0..100_000)
+        .map(|i| noise.eval_1d(i as f64 * 0.0137 - 500.0))
+        .collect();
+
+    assert!(values.iter().all(|value| value.abs() <= 1.0));
+    // Spreads over most of the range
+    assert!(values.iter().any(|&value| value > 0.5));
+    assert!(values.iter().any(|&value| value < -0.5));
+}
+
+#[test]
+fn gradient_matches_finite_differences() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let step = 1e-6;
+    for i in 0..1000 {
+        let x = i as f64 * 0.173 - 80.0;
+        let (value, gradient) = noise.eval_1d_with_gradient(x);
+        let estimate = (noise.eval_1d(x + step) - noise.eval_1d(x - step)) / (2.0 * step);
+
+        assert_eq!(value, noise.eval_1d(x));
+        assert!(
+            (gradient - estimate).abs() < 1e-6,
+            "{} {}",
+            gradient,
+            estimate
+        );
+    }
+}
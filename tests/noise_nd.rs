@@ -0,0 +1,201 @@
+use core::ops::{Add, AddAssign, Div};
+
+use opensimplex_noise_rs::{
+    NoiseVersion, OpenSimplexNoise, OpenSimplexNoise2D, OpenSimplexNoise3D, OpenSimplexNoise4D,
+    OpenSimplexNoiseND, Vec2, Vec3, Vec4, VecMethods, VecN,
+};
+
+const SEED: i64 = 883_279_212_983_182_319;
+const SQUISH_2D: f64 = 0.366_025_403_784_439;
+const SQUISH_3D: f64 = 1.0 / 3.0;
+const SQUISH_4D: f64 = 0.309_016_994_374_947;
+
+// Sum of the attenuations of the contributing lattice points, left alone by the normalization.
+#[derive(Clone, Copy, Default)]
+struct Attenuation(f64);
+
+impl Add for Attenuation {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Attenuation(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Attenuation {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Div<f64> for Attenuation {
+    type Output = Self;
+
+    fn div(self, _: f64) -> Self {
+        self
+    }
+}
+
+fn attenuation(shifted_squared: f64) -> Attenuation {
+    Attenuation((2.0 - shifted_squared).max(0.0).powi(4))
+}
+
+// Scattered points in [-64, 64)^N
+fn points<const N: usize>() -> impl Iterator<Item = [f64; N]> {
+    let mut state: u64 = 3;
+    core::iter::repeat_with(move || {
+        let mut point = [0.0; N];
+        for coord in point.iter_mut() {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            *coord = (state >> 11) as f64 / (1u64 << 53) as f64 * 128.0 - 64.0;
+        }
+        point
+    })
+}
+
+#[test]
+fn searched_lattice_points_match_the_2d_regions() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let hash = noise.lattice_hash();
+
+    for i in 0..1000 {
+        let (x, y) = (i as f64 * 0.173 - 80.0, i as f64 * -0.091 + 30.0);
+        let regions = OpenSimplexNoise2D::evaluate(
+            Vec2::new(x, y),
+            &hash,
+            NoiseVersion::V0_4,
+            |delta: Vec2<f64>, origin: Vec2<f64>, _, _: &_| {
+                let shifted = origin - delta - Vec2::new(SQUISH_2D, SQUISH_2D) * delta.sum();
+                attenuation(shifted.get_attenuation_factor())
+            },
+        );
+        let searched = OpenSimplexNoiseND::<2>::evaluate(
+            VecN::new([x, y]),
+            &hash,
+            NoiseVersion::V0_4,
+            |delta: VecN<f64, 2>, origin: VecN<f64, 2>, _, _: &_| {
+                let shifted = origin - delta - VecN::new([SQUISH_2D; 2]) * delta.sum();
+                attenuation(shifted.get_attenuation_factor())
+            },
+        );
+        assert!((regions.0 - searched.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn searched_lattice_points_match_the_3d_regions() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let hash = noise.lattice_hash();
+
+    for i in 0..1000 {
+        let (x, y, z) = (
+            i as f64 * 0.173 - 80.0,
+            i as f64 * -0.091 + 30.0,
+            i as f64 * 0.057,
+        );
+        let regions = OpenSimplexNoise3D::evaluate(
+            Vec3::new(x, y, z),
+            &hash,
+            NoiseVersion::V0_4,
+            |delta: Vec3<f64>, origin: Vec3<f64>, _, _: &_| {
+                let shifted =
+                    origin - delta - Vec3::new(SQUISH_3D, SQUISH_3D, SQUISH_3D) * delta.sum();
+                attenuation(shifted.get_attenuation_factor())
+            },
+        );
+        let searched = OpenSimplexNoiseND::<3>::evaluate(
+            VecN::new([x, y, z]),
+            &hash,
+            NoiseVersion::V0_4,
+            |delta: VecN<f64, 3>, origin: VecN<f64, 3>, _, _: &_| {
+                let shifted = origin - delta - VecN::new([SQUISH_3D; 3]) * delta.sum();
+                attenuation(shifted.get_attenuation_factor())
+            },
+        );
+        assert!((regions.0 - searched.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn searched_lattice_points_match_the_4d_regions() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let hash = noise.lattice_hash();
+
+    for i in 0..1000 {
+        let t = i as f64;
+        let (x, y, z, w) = (
+            t * 0.173 - 80.0,
+            t * -0.091 + 30.0,
+            t * 0.057,
+            9.0 - t * 0.121,
+        );
+        let regions = OpenSimplexNoise4D::evaluate(
+            Vec4::new(x, y, z, w),
+            &hash,
+            NoiseVersion::V0_4,
+            |delta: Vec4<f64>, origin: Vec4<f64>, _, _: &_| {
+                let squish = Vec4::new(SQUISH_4D, SQUISH_4D, SQUISH_4D, SQUISH_4D);
+                let shifted = origin - delta - squish * delta.sum();
+                attenuation(shifted.get_attenuation_factor())
+            },
+        );
+        let searched = OpenSimplexNoiseND::<4>::evaluate(
+            VecN::new([x, y, z, w]),
+            &hash,
+            NoiseVersion::V0_4,
+            |delta: VecN<f64, 4>, origin: VecN<f64, 4>, _, _: &_| {
+                let shifted = origin - delta - VecN::new([SQUISH_4D; 4]) * delta.sum();
+                attenuation(shifted.get_attenuation_factor())
+            },
+        );
+        assert!((regions.0 - searched.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn higher_dimensions_stay_in_range() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    // Just out of range with the scalar the 5D noise first shipped with
+    let value = noise.eval_5d(
+        -46.535_734_949_289_02,
+        54.238_691_450_138_08,
+        -67.443_996_973_266_96,
+        77.547_163_379_474_13,
+        26.502_979_309_202_203,
+    );
+    assert!(value.abs() <= 1.0, "{}", value);
+
+    for point in points::<5>().take(20_000) {
+        let value = noise.eval_nd(point);
+        assert!(value.abs() <= 1.0, "{:?} {}", point, value);
+    }
+    for point in points::<6>().take(20_000) {
+        let value = noise.eval_nd(point);
+        assert!(value.abs() <= 1.0, "{:?} {}", point, value);
+    }
+}
+
+#[test]
+fn gradient_matches_finite_differences() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let step = 1e-6;
+    for i in 0..200 {
+        let t = i as f64 * 0.173;
+        let point = [t, -t * 0.7, t * 0.3 + 1.0, -t, t * 1.3, 2.0 - t];
+        let (value, gradient) = noise.eval_nd_with_gradient(point);
+        assert_eq!(
+            value,
+            noise.eval_6d(point[0], point[1], point[2], point[3], point[4], point[5])
+        );
+
+        for axis in 0..6 {
+            let (mut ahead, mut behind) = (point, point);
+            ahead[axis] += step;
+            behind[axis] -= step;
+            let estimate = (noise.eval_nd(ahead) - noise.eval_nd(behind)) / (2.0 * step);
+            assert!((gradient[axis] - estimate).abs() < 1e-6);
+        }
+    }
+}
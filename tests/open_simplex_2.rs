@@ -0,0 +1,156 @@
+use opensimplex_noise_rs::{OpenSimplex2F, OpenSimplex2S};
+
+const SEED: i64 = 42;
+
+fn random(state: &mut u64) -> f64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    (*state >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// Scattered points in [-64, 64)^N
+fn points<const N: usize>(count: usize) -> impl Iterator<Item = [f64; N]> {
+    let mut state = N as u64;
+    (0..count).map(move |_| {
+        let mut point = [0.0; N];
+        for coord in point.iter_mut() {
+            *coord = random(&mut state) * 128.0 - 64.0;
+        }
+        point
+    })
+}
+
+fn assert_in_range<const N: usize>(eval: impl Fn([f64; N]) -> f64) {
+    let values: Vec<f64> = points::<N>(100_000).map(eval).collect();
+
+    assert!(values.iter().all(|value| value.abs() <= 1.0));
+    // Spreads over a good part of the range
+    assert!(values.iter().any(|&value| value > 0.5));
+    assert!(values.iter().any(|&value| value < -0.5));
+}
+
+// Walks lines in random directions with tiny steps, a jump shows up as a step much larger than
+// the slope of the noise allows.
+fn assert_continuous<const N: usize>(eval: impl Fn([f64; N]) -> f64) {
+    let step = 1e-5;
+    let mut state = 7;
+    for start in points::<N>(10) {
+        let mut direction = [0.0; N];
+        for coord in direction.iter_mut() {
+            *coord = random(&mut state) * 2.0 - 1.0;
+        }
+        let length = direction
+            .iter()
+            .map(|coord| coord * coord)
+            .sum::<f64>()
+            .sqrt();
+
+        let point_at = |i: u32| {
+            let mut point = start;
+            for (coord, direction) in point.iter_mut().zip(direction.iter()) {
+                *coord += direction / length * step * f64::from(i);
+            }
+            point
+        };
+        let mut previous = eval(point_at(0));
+        for i in 1..50_000 {
+            let value = eval(point_at(i));
+            assert!(
+                (value - previous).abs() < 1e-4,
+                "jump of {} at {:?}",
+                value - previous,
+                point_at(i)
+            );
+            previous = value;
+        }
+    }
+}
+
+// Both sides of `point`, a hair apart along every axis
+fn assert_no_jump_at<const N: usize>(eval: impl Fn([f64; N]) -> f64, point: [f64; N]) {
+    for axis in 0..N {
+        let (mut ahead, mut behind) = (point, point);
+        ahead[axis] += 1e-9;
+        behind[axis] -= 1e-9;
+        assert!((eval(ahead) - eval(behind)).abs() < 1e-7);
+    }
+}
+
+#[test]
+fn fast_stays_in_range() {
+    let noise = OpenSimplex2F::new(Some(SEED));
+    assert_in_range(|[x, y]| noise.eval_2d(x, y));
+    assert_in_range(|[x, y, z]| noise.eval_3d(x, y, z));
+    assert_in_range(|[x, y, z, w]| noise.eval_4d(x, y, z, w));
+}
+
+#[test]
+fn fast_is_continuous() {
+    let noise = OpenSimplex2F::new(Some(SEED));
+    assert_continuous(|[x, y]| noise.eval_2d(x, y));
+    assert_continuous(|[x, y, z]| noise.eval_3d(x, y, z));
+    assert_continuous(|[x, y, z, w]| noise.eval_4d(x, y, z, w));
+}
+
+// Points where a larger kernel reached lattice points the evaluators didn't add
+#[test]
+fn fast_has_no_jumps_where_points_leave_the_kernel() {
+    let noise = OpenSimplex2F::new(Some(SEED));
+    assert_no_jump_at(
+        |[x, y, z]| noise.eval_3d(x, y, z),
+        [
+            1.674_753_957_366_033_5,
+            -16.851_074_515_980_653,
+            14.674_753_957_366_033,
+        ],
+    );
+    assert_no_jump_at(
+        |[x, y, z, w]| noise.eval_4d(x, y, z, w),
+        [
+            0.965_823_788_720_675_3,
+            10.377_803_111_126_35,
+            -14.622_196_888_873_65,
+            -3.141_124_355_879_855_4,
+        ],
+    );
+}
+
+#[test]
+fn smooth_stays_in_range() {
+    let noise = OpenSimplex2S::new(Some(SEED));
+    assert_in_range(|[x, y]| noise.eval_2d(x, y));
+    assert_in_range(|[x, y, z]| noise.eval_3d(x, y, z));
+    assert_in_range(|[x, y, z]| noise.eval_3d_improve_xy(x, y, z));
+    assert_in_range(|[x, y, z]| noise.eval_3d_improve_xz(x, y, z));
+    assert_in_range(|[x, y, z, w]| noise.eval_4d(x, y, z, w));
+}
+
+#[test]
+fn smooth_is_continuous() {
+    let noise = OpenSimplex2S::new(Some(SEED));
+    assert_continuous(|[x, y]| noise.eval_2d(x, y));
+    assert_continuous(|[x, y, z]| noise.eval_3d(x, y, z));
+    assert_continuous(|[x, y, z]| noise.eval_3d_improve_xy(x, y, z));
+    assert_continuous(|[x, y, z, w]| noise.eval_4d(x, y, z, w));
+}
+
+// Changing any of these changes the output for every existing seed
+#[test]
+fn smooth_keeps_its_values() {
+    let noise = OpenSimplex2S::new(Some(SEED));
+    assert_eq!(noise.eval_2d(0.3, -7.9), -0.417_954_381_454_367_25);
+    assert_eq!(noise.eval_3d(0.3, -7.9, 12.4), -0.371_259_526_334_430_27);
+    assert_eq!(
+        noise.eval_3d_improve_xy(0.3, -7.9, 12.4),
+        0.108_063_437_768_251_68
+    );
+    assert_eq!(
+        noise.eval_3d_improve_xz(0.3, -7.9, 12.4),
+        0.000_429_675_088_008_508_54
+    );
+    assert_eq!(
+        noise.eval_4d(0.3, -7.9, 12.4, 5.1),
+        -0.023_480_889_010_490_835
+    );
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "rayon")]
+
+use opensimplex_noise_rs::{HashedOpenSimplexNoise, OpenSimplexNoise, VolumeLayout};
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+#[test]
+fn par_fill_2d_matches_fill_2d() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let (width, height) = (97, 64);
+
+    let mut sequential = vec![0.0; width * height];
+    let mut parallel = vec![0.0; width * height];
+    noise.fill_2d(&mut sequential, width, height, [-3.2, 7.1], 0.07);
+    noise.par_fill_2d(&mut parallel, width, height, [-3.2, 7.1], 0.07);
+    assert_eq!(sequential, parallel);
+
+    let mut sequential = vec![0.0; width * height];
+    let mut parallel = vec![0.0; width * height];
+    noise.fill_2d_f32(&mut sequential, width, height, [-3.2, 7.1], 0.07);
+    noise.par_fill_2d_f32(&mut parallel, width, height, [-3.2, 7.1], 0.07);
+    assert_eq!(sequential, parallel);
+}
+
+#[test]
+fn par_fill_3d_matches_fill_3d() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let size = [17, 9, 24];
+
+    for &layout in &[VolumeLayout::XMajor, VolumeLayout::ZMajor] {
+        let mut sequential = vec![0.0; 17 * 9 * 24];
+        let mut parallel = vec![0.0; 17 * 9 * 24];
+        noise.fill_3d(&mut sequential, size, [1.5, -0.4, 12.0], 0.11, layout);
+        noise.par_fill_3d(&mut parallel, size, [1.5, -0.4, 12.0], 0.11, layout);
+        assert_eq!(sequential, parallel);
+    }
+}
+
+#[test]
+fn hashed_par_fills_match_fills() {
+    let noise = HashedOpenSimplexNoise::new(Some(SEED));
+    let (width, height) = (97, 64);
+    let mut sequential = vec![0.0; width * height];
+    let mut parallel = vec![0.0; width * height];
+    noise.fill_2d(&mut sequential, width, height, [-3.2, 7.1], 0.07);
+    noise.par_fill_2d(&mut parallel, width, height, [-3.2, 7.1], 0.07);
+    assert_eq!(sequential, parallel);
+
+    let size = [17, 9, 24];
+    let mut sequential = vec![0.0; 17 * 9 * 24];
+    let mut parallel = vec![0.0; 17 * 9 * 24];
+    noise.fill_3d(
+        &mut sequential,
+        size,
+        [1.5, -0.4, 12.0],
+        0.11,
+        VolumeLayout::ZMajor,
+    );
+    noise.par_fill_3d(
+        &mut parallel,
+        size,
+        [1.5, -0.4, 12.0],
+        0.11,
+        VolumeLayout::ZMajor,
+    );
+    assert_eq!(sequential, parallel);
+}
@@ -0,0 +1,150 @@
+use opensimplex_noise_rs::{HashPeriod, HashedOpenSimplexNoise, NoiseVersion, OpenSimplexNoise};
+
+const SEED: i64 = 883_279_212_983_182_319;
+const STEPS: u32 = 16;
+
+// Dyadic steps are exactly representable, so shifted coordinates keep the same fractional part
+fn steps(period: u32) -> impl Iterator<Item = f64> {
+    (0..period * STEPS).map(|i| f64::from(i) / f64::from(STEPS))
+}
+
+#[test]
+fn periodic_2d_matches_on_opposite_edges() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let period = [8, 5];
+    let (width, height) = (f64::from(period[0]), f64::from(period[1]));
+
+    for x in steps(period[0]) {
+        assert_eq!(
+            noise.eval_2d_periodic(x, 0.0, period),
+            noise.eval_2d_periodic(x, height, period)
+        );
+    }
+    for y in steps(period[1]) {
+        assert_eq!(
+            noise.eval_2d_periodic(0.0, y, period),
+            noise.eval_2d_periodic(width, y, period)
+        );
+    }
+}
+
+// `V0_3` jumps along some simplex boundaries, which the seam is one of
+#[test]
+fn periodic_2d_is_continuous_across_the_seam() {
+    let noise = OpenSimplexNoise::with_version(Some(SEED), NoiseVersion::V0_4);
+    let period = [6, 6];
+    let epsilon = 1e-7;
+
+    for t in steps(period[0]) {
+        let inside = noise.eval_2d_periodic(6.0 - epsilon, t + 0.3, period);
+        let outside = noise.eval_2d_periodic(epsilon, t + 0.3, period);
+        assert!((inside - outside).abs() < 1e-5);
+
+        let inside = noise.eval_2d_periodic(t + 0.3, 6.0 - epsilon, period);
+        let outside = noise.eval_2d_periodic(t + 0.3, epsilon, period);
+        assert!((inside - outside).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn periodic_2d_repeats_for_negative_coordinates() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let period = [4, 7];
+
+    for x in steps(period[0]) {
+        for y in steps(period[1]) {
+            assert_eq!(
+                noise.eval_2d_periodic(x, y, period),
+                noise.eval_2d_periodic(x - 4.0, y - 14.0, period)
+            );
+        }
+    }
+}
+
+#[test]
+fn periodic_2d_is_not_flat() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let values: Vec<f64> = steps(4)
+        .map(|t| noise.eval_2d_periodic(t, t * 0.5, [4, 4]))
+        .collect();
+
+    assert!(values.iter().any(|&value| value > 0.1));
+    assert!(values.iter().any(|&value| value < -0.1));
+}
+
+#[test]
+fn periodic_3d_matches_on_opposite_faces() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let period = [3, 4, 5];
+
+    for a in steps(3) {
+        for b in steps(4) {
+            assert_eq!(
+                noise.eval_3d_periodic(0.0, a, b, period),
+                noise.eval_3d_periodic(3.0, a, b, period)
+            );
+            assert_eq!(
+                noise.eval_3d_periodic(a, 0.0, b, period),
+                noise.eval_3d_periodic(a, 4.0, b, period)
+            );
+            assert_eq!(
+                noise.eval_3d_periodic(a, b, 0.0, period),
+                noise.eval_3d_periodic(a, b, 5.0, period)
+            );
+        }
+    }
+}
+
+// `V0_3` jumps along some simplex boundaries, which the seam is one of
+#[test]
+fn periodic_3d_is_continuous_across_the_seam() {
+    let noise = OpenSimplexNoise::with_version(Some(SEED), NoiseVersion::V0_4);
+    let period = [5, 5, 5];
+    let epsilon = 1e-7;
+
+    for t in steps(5) {
+        let inside = noise.eval_3d_periodic(5.0 - epsilon, t + 0.3, 1.7, period);
+        let outside = noise.eval_3d_periodic(epsilon, t + 0.3, 1.7, period);
+        assert!((inside - outside).abs() < 1e-5);
+
+        let inside = noise.eval_3d_periodic(2.1, t + 0.3, 5.0 - epsilon, period);
+        let outside = noise.eval_3d_periodic(2.1, t + 0.3, epsilon, period);
+        assert!((inside - outside).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn periodic_2d_tiles_past_the_default_hash_period() {
+    let noise = OpenSimplexNoise::new(Some(SEED)).with_hash_period(HashPeriod::Cells2048);
+    let period = [300, 3];
+
+    for t in steps(3) {
+        assert_eq!(
+            noise.eval_2d_periodic(t * 100.0, t, period),
+            noise.eval_2d_periodic(t * 100.0 + 300.0, t - 3.0, period)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "hash period")]
+fn periodic_2d_rejects_periods_past_the_hash_period() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    noise.eval_2d_periodic(0.5, 0.5, [300, 3]);
+}
+
+// Hashed lattices never repeat on their own, so any period goes
+#[test]
+fn hashed_periodic_tiles() {
+    let noise = HashedOpenSimplexNoise::new(Some(SEED));
+    for t in steps(3) {
+        assert_eq!(
+            noise.eval_2d_periodic(t * 100.0, t, [300, 3]),
+            noise.eval_2d_periodic(t * 100.0 + 300.0, t - 3.0, [300, 3])
+        );
+        assert_eq!(
+            noise.eval_3d_periodic(t, t * 2.0, t * 150.0, [3, 5, 1000]),
+            noise.eval_3d_periodic(t - 3.0, t * 2.0 + 5.0, t * 150.0 + 1000.0, [3, 5, 1000])
+        );
+    }
+}
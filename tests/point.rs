@@ -0,0 +1,57 @@
+use opensimplex_noise_rs::{
+    HashedOpenSimplexNoise, NoisePoint, OpenSimplexNoise, Vec2, Vec3, Vec4,
+};
+
+const SEED: i64 = 883_279_212_983_182_319;
+
+// Written once for every dimension
+fn sum_along<P: NoisePoint>(noise: &OpenSimplexNoise, points: impl Iterator<Item = P>) -> f64 {
+    points.map(|point| noise.eval(point)).sum()
+}
+
+#[test]
+fn generic_code_matches_the_per_dimension_methods() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let coord = |i: i32| f64::from(i) * 0.173 - 11.0;
+
+    assert_eq!(
+        sum_along(&noise, (0..100).map(coord)),
+        (0..100).map(|i| noise.eval_1d(coord(i))).sum::<f64>()
+    );
+    assert_eq!(
+        sum_along(&noise, (0..100).map(|i| [coord(i), -coord(i)])),
+        (0..100)
+            .map(|i| noise.eval_2d(coord(i), -coord(i)))
+            .sum::<f64>()
+    );
+    assert_eq!(
+        sum_along(
+            &noise,
+            (0..100).map(|i| [coord(i), 0.5, -coord(i), 2.0, 1.5, coord(i)])
+        ),
+        (0..100)
+            .map(|i| noise.eval_6d(coord(i), 0.5, -coord(i), 2.0, 1.5, coord(i)))
+            .sum::<f64>()
+    );
+}
+
+#[test]
+fn point_types_agree() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let hashed = HashedOpenSimplexNoise::new(Some(SEED));
+    for i in 0..100 {
+        let (x, y, z, w) = (i as f64 * 0.173, i as f64 * -0.091, i as f64 * 0.057, 1.5);
+
+        assert_eq!(noise.eval((x, y)), noise.eval(Vec2::new(x, y)));
+        assert_eq!(noise.eval((x, y, z)), noise.eval(Vec3::new(x, y, z)));
+        assert_eq!(noise.eval((x, y, z, w)), noise.eval(Vec4::new(x, y, z, w)));
+        assert_eq!(noise.eval([x]), noise.eval(x));
+        assert_eq!(hashed.eval([x, y, z]), hashed.eval_3d(x, y, z));
+
+        let (value, gradient) = noise.eval_with_gradient(Vec3::new(x, y, z));
+        assert_eq!(
+            (value, [gradient.x, gradient.y, gradient.z]),
+            noise.eval_with_gradient((x, y, z))
+        );
+    }
+}
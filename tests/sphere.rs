@@ -0,0 +1,86 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use opensimplex_noise_rs::{
+    CubeFace, HashedOpenSimplexNoise, OpenSimplexNoise, Sphere, SpherePoint,
+};
+
+const SEED: i64 = 883_279_212_983_182_319;
+const PLANET: Sphere = Sphere::new(6_000.0, 0.002);
+
+fn lat_long(latitude: f64, longitude: f64) -> SpherePoint {
+    SpherePoint::LatLong {
+        latitude,
+        longitude,
+    }
+}
+
+#[test]
+fn no_seam_nor_pinching() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    for i in 0..100 {
+        let latitude = i as f64 * 0.031 - 1.5;
+        let seam = noise.eval_sphere(&PLANET, lat_long(latitude, -PI));
+        assert!((seam - noise.eval_sphere(&PLANET, lat_long(latitude, PI))).abs() < 1e-9);
+
+        let longitude = i as f64 * 0.0628;
+        let pole = noise.eval_sphere(&PLANET, lat_long(FRAC_PI_2, 0.0));
+        assert!((pole - noise.eval_sphere(&PLANET, lat_long(FRAC_PI_2, longitude))).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn addressing_schemes_agree() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    for i in 0..100 {
+        let t = i as f64 * 0.02 - 1.0;
+        let on_face = |face, u| noise.eval_sphere(&PLANET, SpherePoint::CubeFace { face, u, v: t });
+
+        // Shared edge of two faces
+        let edge = on_face(CubeFace::PositiveX, 1.0);
+        assert!((edge - on_face(CubeFace::NegativeZ, -1.0)).abs() < 1e-9);
+
+        let direction =
+            noise.eval_sphere(&PLANET, SpherePoint::Direction([3.0, -3.0 * t, -3.0 * t]));
+        assert!((direction - on_face(CubeFace::PositiveX, t)).abs() < 1e-9);
+    }
+
+    let equator = noise.eval_sphere(&PLANET, lat_long(0.0, 0.0));
+    assert!(
+        (equator - noise.eval_sphere(&PLANET, SpherePoint::Direction([0.5, 0.0, 0.0]))).abs()
+            < 1e-9
+    );
+}
+
+#[test]
+fn frequency_is_in_surface_units() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let point = lat_long(0.3, 1.1);
+    let value = noise.eval_sphere(&PLANET, point);
+
+    // Twice the radius at half the frequency reads the same noise
+    assert!((value - noise.eval_sphere(&Sphere::new(12_000.0, 0.001), point)).abs() < 1e-9);
+}
+
+#[test]
+fn animation_changes_over_time() {
+    let noise = OpenSimplexNoise::new(Some(SEED));
+    let point = lat_long(0.3, 1.1);
+    assert_ne!(
+        noise.eval_sphere_animated(&PLANET, point, 0.0),
+        noise.eval_sphere_animated(&PLANET, point, 0.5)
+    );
+}
+
+#[test]
+fn hashed_sphere_has_no_seam() {
+    let noise = HashedOpenSimplexNoise::new(Some(SEED));
+    for i in 0..100 {
+        let latitude = i as f64 * 0.031 - 1.5;
+        let seam = noise.eval_sphere(&PLANET, lat_long(latitude, -PI));
+        assert!((seam - noise.eval_sphere(&PLANET, lat_long(latitude, PI))).abs() < 1e-9);
+    }
+    assert_ne!(
+        noise.eval_sphere_animated(&PLANET, lat_long(0.3, 1.1), 0.0),
+        noise.eval_sphere_animated(&PLANET, lat_long(0.3, 1.1), 0.5)
+    );
+}